@@ -103,10 +103,41 @@ pub enum RmrkError {
     WithdrawalFailed,
     // ここから追加
     InvalidAccountId,
-    TimeHasNotPassed,
+    /// Milliseconds left before the action can be repeated
+    TimeHasNotPassed(u64),
     AlreadyHadOneNft,
     NotEnoughApple,
-    NotEnoughMoney
+    NotEnoughMoney,
+    CommitmentNotFound,
+    CommitmentPending,
+    InvalidReveal,
+    RandomnessNotRevealed,
+    RandomnessUnavailable,
+    RevealTooEarly,
+    ItemNotFound,
+    NotEnoughItems,
+    TooManyItems,
+    CurrencyCallFailed,
+    CurrencyNotApproved,
+    InvalidLockId,
+    NotEnoughStake,
+    StakeLocked,
+    PetIsDead,
+    PetIsNotDead,
+    InvalidLifecycleStage,
+    TooManyActions,
+    MoneyOverflow,
+    ListingNotFound,
+    SaleNotActive,
+    OutOfStock,
+    PurchaseLimitReached,
+    InvalidPayment,
+    QuestNotFound,
+    QuestNotCompleted,
+    QuestAlreadyClaimed,
+    RateLimited,
+    BlockCapReached,
+    StatOutOfRange,
 }
 
 impl ToString for RmrkError {
@@ -144,10 +175,40 @@ impl ToString for RmrkError {
             RmrkError::WithdrawalFailed => String::from("WithdrawalFailed"),
             // ここから追加
             RmrkError::InvalidAccountId => String::from("InvalidAccountId"),
-            RmrkError::TimeHasNotPassed => String::from("TimeHasNotPassed"),
+            RmrkError::TimeHasNotPassed(_) => String::from("TimeHasNotPassed"),
             RmrkError::AlreadyHadOneNft => String::from("TimeHasNotPassed"),
             RmrkError::NotEnoughApple => String::from("NotEnoughApple"),
             RmrkError::NotEnoughMoney => String::from("NotEnoughMoney"),
+            RmrkError::CommitmentNotFound => String::from("CommitmentNotFound"),
+            RmrkError::CommitmentPending => String::from("CommitmentPending"),
+            RmrkError::InvalidReveal => String::from("InvalidReveal"),
+            RmrkError::RandomnessNotRevealed => String::from("RandomnessNotRevealed"),
+            RmrkError::RandomnessUnavailable => String::from("RandomnessUnavailable"),
+            RmrkError::RevealTooEarly => String::from("RevealTooEarly"),
+            RmrkError::ItemNotFound => String::from("ItemNotFound"),
+            RmrkError::NotEnoughItems => String::from("NotEnoughItems"),
+            RmrkError::TooManyItems => String::from("TooManyItems"),
+            RmrkError::CurrencyCallFailed => String::from("CurrencyCallFailed"),
+            RmrkError::CurrencyNotApproved => String::from("CurrencyNotApproved"),
+            RmrkError::InvalidLockId => String::from("InvalidLockId"),
+            RmrkError::NotEnoughStake => String::from("NotEnoughStake"),
+            RmrkError::StakeLocked => String::from("StakeLocked"),
+            RmrkError::PetIsDead => String::from("PetIsDead"),
+            RmrkError::PetIsNotDead => String::from("PetIsNotDead"),
+            RmrkError::InvalidLifecycleStage => String::from("InvalidLifecycleStage"),
+            RmrkError::TooManyActions => String::from("TooManyActions"),
+            RmrkError::MoneyOverflow => String::from("MoneyOverflow"),
+            RmrkError::ListingNotFound => String::from("ListingNotFound"),
+            RmrkError::SaleNotActive => String::from("SaleNotActive"),
+            RmrkError::OutOfStock => String::from("OutOfStock"),
+            RmrkError::PurchaseLimitReached => String::from("PurchaseLimitReached"),
+            RmrkError::InvalidPayment => String::from("InvalidPayment"),
+            RmrkError::QuestNotFound => String::from("QuestNotFound"),
+            RmrkError::QuestNotCompleted => String::from("QuestNotCompleted"),
+            RmrkError::QuestAlreadyClaimed => String::from("QuestAlreadyClaimed"),
+            RmrkError::RateLimited => String::from("RateLimited"),
+            RmrkError::BlockCapReached => String::from("BlockCapReached"),
+            RmrkError::StatOutOfRange => String::from("StatOutOfRange"),
         }
     }
 }
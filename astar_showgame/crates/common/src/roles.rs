@@ -2,3 +2,4 @@ use openbrush::contracts::access_control::*;
 
 pub const ADMIN: RoleType = DEFAULT_ADMIN_ROLE;
 pub const CONTRIBUTOR: RoleType = 1;
+pub const GAME_MASTER: RoleType = 2;
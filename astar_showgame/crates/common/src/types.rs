@@ -5,6 +5,7 @@ use openbrush::{
     contracts::psp34::Id,
     traits::{
         AccountId,
+        Balance,
         String,
     },
 };
@@ -19,6 +20,9 @@ pub type SlotId = u32;
 pub type PartId = u32;
 pub type AssetId = u32;
 pub type EquippableGroupId = u32;
+pub type ItemId = u32;
+pub type ListingId = u32;
+pub type QuestId = u32;
 
 /// Part's details
 #[derive(scale::Encode, scale::Decode, Default, Debug)]
@@ -101,4 +105,815 @@ pub struct Status {
     pub health: u32,
 
     pub happy: u32,
-}
\ No newline at end of file
+}
+
+impl Status {
+    /// Return the status once `delta` is applied. Stats saturate at their bounds
+    pub fn apply(&self, delta: &StatusDelta, bounds: &StatBounds) -> Status {
+        let change = |value: u32, delta: i32| {
+            let value = if delta >= 0 {
+                value.saturating_add(delta.unsigned_abs())
+            } else {
+                value.saturating_sub(delta.unsigned_abs())
+            };
+            bounds.clamp(value)
+        };
+        Status {
+            hungry: change(self.hungry, delta.hungry),
+            health: change(self.health, delta.health),
+            happy: change(self.happy, delta.happy),
+        }
+    }
+
+    /// Return the sum of health and happiness minus hunger, saturating at 0
+    pub fn total(&self) -> u32 {
+        self.health
+            .saturating_add(self.happy)
+            .saturating_sub(self.hungry)
+    }
+
+    /// Return the condition matching the total status
+    pub fn condition(&self) -> Condition {
+        match self.total() {
+            total if total < 100 => Condition::Bad,
+            total if total < 200 => Condition::Normal,
+            _ => Condition::Good,
+        }
+    }
+
+    /// Return the mood of the pet. Hunger takes over happiness
+    pub fn mood(&self) -> Mood {
+        if self.hungry >= 70 {
+            Mood::Hungry
+        } else if self.happy < 30 {
+            Mood::Sad
+        } else if self.happy < 70 {
+            Mood::Content
+        } else {
+            Mood::Happy
+        }
+    }
+}
+
+/// Highest value a stat of a pet can reach unless configured otherwise
+pub const DEFAULT_MAX_STAT: u32 = 100;
+
+/// Used to define the range `0..=max` every stat of a pet is kept in
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct StatBounds {
+    /// Highest value of a stat
+    pub max: u32,
+}
+
+impl Default for StatBounds {
+    fn default() -> Self {
+        Self {
+            max: DEFAULT_MAX_STAT,
+        }
+    }
+}
+
+impl StatBounds {
+    /// Return the stat clamped into the bounds
+    pub fn clamp(&self, value: u32) -> u32 {
+        value.min(self.max)
+    }
+
+    /// Return the status with every stat clamped into the bounds
+    pub fn clamp_status(&self, status: &Status) -> Status {
+        Status {
+            hungry: self.clamp(status.hungry),
+            health: self.clamp(status.health),
+            happy: self.clamp(status.happy),
+        }
+    }
+
+    /// Return true if every stat of the status is within the bounds
+    pub fn contains(&self, status: &Status) -> bool {
+        status.hungry <= self.max && status.health <= self.max && status.happy <= self.max
+    }
+}
+
+/// Used to define the condition of a pet from its total status
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Condition {
+    /// Total status below 100
+    Bad,
+
+    /// Total status from 100 to 199
+    Normal,
+
+    /// Total status of 200 or more
+    Good,
+}
+
+/// Used to define the mood of a pet
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Mood {
+    /// Hunger of 70 or more
+    Hungry,
+
+    /// Happiness below 30
+    Sad,
+
+    /// Happiness from 30 to 69
+    Content,
+
+    /// Happiness of 70 or more
+    Happy,
+}
+
+/// Used to read everything about a pet at once
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PetView {
+    /// Status of the pet once the elapsed time is taken into account
+    pub status: Status,
+
+    /// Sum of health and happiness minus hunger
+    pub total_status: u32,
+
+    pub condition: Condition,
+
+    pub mood: Mood,
+
+    pub stage: LifecycleStage,
+
+    /// Time the pet last ate
+    pub last_eaten: u64,
+
+    /// Uri of the pet for its current condition
+    pub uri: String,
+}
+
+/// Used to define a relative change of the status of a pet
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct StatusDelta {
+    pub hungry: i32,
+
+    pub health: i32,
+
+    pub happy: i32,
+}
+
+/// Used to define how an outcome changes the status of a pet
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum StatusEffect {
+    /// Change the current status of the pet
+    Change(StatusDelta),
+
+    /// Replace the status of the pet
+    Set(Status),
+}
+
+/// Used to define one entry of the weighted outcome table for feeding
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct FeedOutcome {
+    /// Chance of this outcome relative to the sum of all weights
+    pub weight: u32,
+
+    /// Effect of this outcome on the pet
+    pub effect: StatusEffect,
+}
+
+/// Used to define what happens to a pet when an item is used on it
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ItemEffect {
+    /// Apply the same effect every time
+    Apply(StatusEffect),
+
+    /// Roll an outcome from the feeding outcome table
+    RollFeedOutcome,
+
+    /// Bring a dead pet back to life as a newborn baby with the status.
+    /// Can only be used on dead pets
+    Revive(Status),
+}
+
+/// Item's details
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Item {
+    /// Name of the item
+    pub name: String,
+
+    /// Price of one item in the in-game currency
+    pub price: u64,
+
+    /// Effect of the item on the pet it is used on
+    pub effect: ItemEffect,
+
+    /// Time in milliseconds before the item can be used on the same pet again
+    pub cooldown: u64,
+}
+
+/// Used to name an action that can only be repeated after some time
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Cooldown {
+    /// Using an item that rolls the feeding outcome table on a pet
+    Feed,
+
+    /// Claiming the daily bonus of an account
+    DailyBonus,
+
+    /// Playing with a pet
+    Play,
+
+    /// Breeding a pet
+    Breed,
+
+    /// Using the item on a pet. Its duration is the cooldown of the item
+    Item(ItemId),
+}
+
+/// Used to define what a cooldown is tracked for
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum CooldownSubject {
+    Token(Id),
+    Account(AccountId),
+}
+
+/// Used to name an interaction between two pets
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum PetInteraction {
+    /// Playing together, which makes both pets happier
+    Play,
+
+    /// Breeding a child from both pets
+    Breed,
+}
+
+/// Used to define the asset rendered for pets from a total status
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ConditionTier {
+    /// Lowest total status of the pets in this tier
+    pub threshold: u32,
+
+    /// Collection asset rendered for the pets in this tier
+    pub asset_id: AssetId,
+}
+
+/// Used to define the stage of life of a pet
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum LifecycleStage {
+    #[default]
+    Egg,
+    Baby,
+    Adult,
+    Elder,
+    Dead,
+}
+
+/// Used to define when a pet moves to its next stage of life
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct LifecycleConfig {
+    /// Age in milliseconds at which an egg hatches
+    pub hatch_age: u64,
+
+    /// Age in milliseconds from which a baby grows up if it is well cared for
+    pub adult_age: u64,
+
+    /// Age in milliseconds at which an adult becomes an elder
+    pub elder_age: u64,
+
+    /// Total status a baby needs to grow up
+    pub min_care: u32,
+}
+
+impl Default for LifecycleConfig {
+    fn default() -> Self {
+        Self {
+            hatch_age: 60 * 60 * 1000,
+            adult_age: 3 * 24 * 60 * 60 * 1000,
+            elder_age: 30 * 24 * 60 * 60 * 1000,
+            min_care: 100,
+        }
+    }
+}
+
+/// Used to define how the status of a pet decays over time
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct DecayConfig {
+    /// Length of one decay step in milliseconds
+    pub time_unit: u64,
+
+    /// Time in milliseconds after feeding before the status starts to decay
+    pub grace_period: u64,
+
+    /// Hunger gained per time unit
+    pub hungry_rate: u32,
+
+    /// Health lost per time unit
+    pub health_rate: u32,
+
+    /// Happiness lost per time unit
+    pub happy_rate: u32,
+
+    /// Hunger never grows above this value
+    pub hungry_cap: u32,
+
+    /// Health never decays below this value
+    pub health_floor: u32,
+
+    /// Happiness never decays below this value
+    pub happy_floor: u32,
+}
+
+impl Default for DecayConfig {
+    fn default() -> Self {
+        Self {
+            time_unit: 60 * 1000,
+            grace_period: 0,
+            hungry_rate: 5,
+            health_rate: 5,
+            happy_rate: 5,
+            hungry_cap: 100,
+            health_floor: 0,
+            happy_floor: 0,
+        }
+    }
+}
+
+/// Used to define what a claim of the daily bonus pays
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct BonusReward {
+    /// In-game currency credited to the account
+    pub money: u64,
+
+    /// Item id and number of items added to the inventory of the account
+    pub item: Option<(ItemId, u32)>,
+}
+
+/// Used to define the rewards of consecutive daily bonus claims
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct BonusConfig {
+    /// Reward of each day of the streak. The last reward repeats for longer streaks
+    pub rewards: Vec<BonusReward>,
+
+    /// Time in milliseconds after the daily bonus cooldown during which the streak goes on
+    pub claim_window: u64,
+}
+
+impl Default for BonusConfig {
+    fn default() -> Self {
+        Self {
+            rewards: [BonusReward {
+                money: 100,
+                item: None,
+            }]
+            .to_vec(),
+            claim_window: 24 * 60 * 60 * 1000,
+        }
+    }
+}
+
+/// Used to name a leaderboard of the game
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum LeaderboardKind {
+    /// Pets ranked by their total status
+    PetStatus,
+
+    /// Players ranked by their in-game currency, staked currency included
+    Wealth,
+
+    /// Players ranked by their daily bonus streak
+    BonusStreak,
+}
+
+/// Used to define who is ranked on a leaderboard
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum LeaderboardSubject {
+    Pet(Id),
+    Player(AccountId),
+}
+
+/// Leaderboard entry's details
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct LeaderboardEntry {
+    /// Pet or player ranked
+    pub subject: LeaderboardSubject,
+
+    /// Score the entry is ranked by
+    pub score: u64,
+}
+
+/// Used to define where the pet game draws its random numbers from
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum RandomnessKind {
+    /// Hash of the block timestamp and a salt. Block authors can predict it
+    #[default]
+    Hash,
+
+    /// Secret committed by the pet owner before feeding and revealed when feeding, mixed with
+    /// the hash of the block. `vrf` also mixes in the output of the runtime VRF
+    CommitReveal { vrf: bool },
+
+    /// Verifiable random function exposed by the runtime through a chain extension
+    ChainExtension,
+}
+
+/// Used to define a lock duration stakers can choose
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct LockOption {
+    /// Time in milliseconds the stake can't be withdrawn
+    pub duration: u64,
+
+    /// Reward multiplier in basis points. 10_000 is no boost
+    pub boost: u32,
+}
+
+/// Used to define how staking is rewarded
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct StakingConfig {
+    /// Yearly reward rate of an unboosted stake in basis points
+    pub apr: u32,
+
+    /// Lock durations stakers can choose from, referred to by their index
+    pub lock_options: Vec<LockOption>,
+}
+
+/// Stake of an account for one lock option
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct StakePosition {
+    /// Staked in-game currency
+    pub amount: u64,
+
+    /// Reward multiplier in basis points applied to this stake
+    pub boost: u32,
+
+    /// Time until which the stake can't be withdrawn
+    pub unlock_at: u64,
+
+    /// Accumulated reward per weight already accounted for this stake
+    pub reward_per_weight_paid: u128,
+
+    /// Rewards accounted for but not claimed yet
+    pub pending_rewards: u64,
+
+    /// Season the stake was stored in
+    pub season: u32,
+}
+
+/// Used to name a player action run by a batch
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum GameAction {
+    /// Feed an apple to the pet
+    EatAnApple(Id),
+
+    /// Use the item on the pet
+    UseItem(Id, ItemId),
+
+    /// Buy a quantity of the item
+    BuyItem(ItemId, u32),
+
+    /// Persist the decayed status of the pet
+    Checkpoint(Id),
+
+    /// Claim the daily bonus
+    DailyBonus,
+
+    /// Claim the rewards of the stake with the lock option
+    ClaimStakingRewards(Option<u32>),
+}
+
+/// Used to report what an action of a batch did
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ActionResult {
+    /// The action succeeded without a value to report
+    Done,
+
+    /// The action paid the in-game currency to the caller
+    Claimed(u64),
+}
+
+/// Used to define what a shop listing is paid with
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ShopCurrency {
+    /// Native balance transferred with the purchase
+    Native,
+
+    /// In-game currency of the internal ledger
+    Ledger,
+
+    /// PSP22 token the buyer approved the game to spend
+    Psp22(AccountId),
+}
+
+/// Used to define an item on sale in the shop
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Listing {
+    /// Item delivered to the buyer
+    pub item_id: ItemId,
+
+    /// Price of one item in `currency`
+    pub price: Balance,
+
+    pub currency: ShopCurrency,
+
+    /// Items left for sale. `None` is unlimited
+    pub stock: Option<u32>,
+
+    /// Time from which the listing can be bought
+    pub starts_at: u64,
+
+    /// Time at which the sale ends. `None` never ends
+    pub ends_at: Option<u64>,
+
+    /// Most items one account can buy from the listing. `None` is unlimited
+    pub limit_per_account: Option<u32>,
+}
+
+/// Used to define what a player must reach to complete a quest
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum QuestCondition {
+    /// Feed pets this number of times
+    Feed(u32),
+
+    /// Keep the health of the pet at or above `health` on each of the last `days` days
+    KeepHealth { health: u32, days: u32 },
+
+    /// Have `amount` of in-game currency staked and locked for at least `lock` more
+    /// milliseconds. Stakes which can be withdrawn sooner don't count
+    Stake { amount: u64, lock: u64 },
+
+    /// Reach this daily bonus streak
+    BonusStreak(u32),
+}
+
+impl QuestCondition {
+    /// Return true if the condition is reached by a pet rather than by its owner
+    pub fn is_per_pet(&self) -> bool {
+        matches!(self, QuestCondition::KeepHealth { .. })
+    }
+}
+
+/// Quest's details
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Quest {
+    /// Name of the quest
+    pub name: String,
+
+    pub condition: QuestCondition,
+
+    /// In-game currency rewarded to the player
+    pub money: u64,
+
+    /// Items rewarded to the player, with their amount
+    pub items: Vec<(ItemId, u32)>,
+
+    /// Attribute set on the pet as an achievement badge. `None` gives no badge
+    pub achievement: Option<String>,
+}
+
+/// Used to define the window an action budget is counted over
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum RateWindow {
+    /// Window lasting this number of blocks
+    Blocks(u32),
+
+    /// Window lasting this number of milliseconds
+    Time(u64),
+}
+
+/// Used to define how often one account can run an action
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ActionBudget {
+    /// Most calls an account can make in one window
+    pub max_calls: u32,
+
+    /// Window starting at the first call of the account, after which its budget is refilled
+    pub window: RateWindow,
+}
+
+/// Used to record why the status of a pet changed
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum StatusCause {
+    /// The status decayed over time
+    Decay,
+
+    /// An item was used on the pet
+    Item(ItemId),
+
+    /// The pet played with another pet
+    Play,
+
+    /// The pet was born from breeding
+    Birth,
+
+    /// A game master changed the status
+    GameMaster,
+}
+
+/// Entry of the status history of a pet
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct StatusRecord {
+    /// Time the status was stored
+    pub timestamp: u64,
+
+    pub status: Status,
+
+    pub cause: StatusCause,
+}
+
+/// Used to define the share of the balances of the accounts kept when a season ends
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct CarryOver {
+    /// Share of the in-game currency kept, in basis points
+    pub money: u32,
+
+    /// Share of each item kept, in basis points
+    pub items: u32,
+
+    /// Whether the stakes are kept. They are kept whole or reset, so the stakes of an account
+    /// always add up to its staked currency
+    pub stakes: bool,
+}
+
+/// Scale of the factor of a `CarryIndex`
+pub const CARRY_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/// Lowest factor of a `CarryIndex`. Below it the index starts a new epoch to keep its precision
+pub const MIN_CARRY_FACTOR: u128 = 1_000_000_000;
+
+/// Used to define the share of a balance kept over all the seasons since an epoch started,
+/// so a balance is carried over any number of seasons at once
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct CarryIndex {
+    /// Number of times the factor started over
+    pub epoch: u32,
+
+    /// Share kept since the epoch started, scaled by `CARRY_PRECISION`
+    pub factor: u128,
+}
+
+impl Default for CarryIndex {
+    fn default() -> Self {
+        Self {
+            epoch: 0,
+            factor: CARRY_PRECISION,
+        }
+    }
+}
+
+/// Used to define how much of the balances the seasons before a season kept in total
+#[derive(scale::Encode, scale::Decode, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct SeasonIndex {
+    /// Index of the in-game currency
+    pub money: CarryIndex,
+
+    /// Index of the items
+    pub items: CarryIndex,
+
+    /// Number of seasons that reset the stakes
+    pub stake_resets: u32,
+}
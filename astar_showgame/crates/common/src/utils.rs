@@ -30,6 +30,9 @@ use openbrush::{
     },
 };
 
+#[openbrush::wrapper]
+pub type UtilsRef = dyn Utils;
+
 /// Trait definitions for Utils functions
 #[openbrush::trait_definition]
 pub trait Utils {
@@ -41,6 +44,10 @@ pub trait Utils {
     #[ink(message)]
     fn withdraw(&mut self) -> Result<()>;
 
+    /// Set an attribute of the token.
+    #[ink(message)]
+    fn set_token_attribute(&mut self, id: Id, key: String, value: String) -> Result<()>;
+
     /// Ensure that token exists
     fn ensure_exists_and_get_owner(&self, id: &Id) -> Result<AccountId>;
 
@@ -81,6 +88,15 @@ where
             .map_err(|_| RmrkError::WithdrawalFailed)?;
         Ok(())
     }
+
+    /// Set an attribute of the token
+    #[modifiers(only_role(CONTRIBUTOR))]
+    default fn set_token_attribute(&mut self, id: Id, key: String, value: String) -> Result<()> {
+        self.ensure_exists_and_get_owner(&id)?;
+        self.data::<metadata::Data>()._set_attribute(id, key, value);
+        Ok(())
+    }
+
     /// Check if token is minted. Return the owner
     default fn ensure_exists_and_get_owner(&self, id: &Id) -> Result<AccountId> {
         let token_owner = self
@@ -13,141 +13,12 @@ use openbrush::{
     },
 };
 
-use openbrush::traits::Balance;
-
 #[openbrush::wrapper]
 pub type MultiAssetRef = dyn MultiAsset;
 
 /// Trait definitions for MultiAsset ink! messages
 #[openbrush::trait_definition]
 pub trait MultiAsset {
-    #[ink(message)]
-    fn set_default(&mut self, account_id: AccountId) -> Result<()>;
-    
-    #[ink(message)]
-    fn set_status(
-        &mut self,
-        token_id: Id, 
-        hungry: u32,
-        health: u32,
-        happy: u32
-    ) -> Result<()>;
-
-    #[ink(message)]
-    fn set_full_status(&mut self, token_id: Id) -> Result<()>;
-
-    #[ink(message)]
-    fn set_death_status(&mut self, token_id: Id) -> Result<()>;
-
-    #[ink(message)]
-    fn set_lucky_status(&mut self, token_id: Id) -> Result<()>;
-
-    #[ink(message)]
-    fn get_status(&self, token_id: Id) -> Option<Status>;
-
-    #[ink(message)]
-    fn get_current_status(&self, token_id: Id) -> Option<Status>;
-
-    #[ink(message)]
-    fn add_twenty(&mut self, token_id: Id) -> Result<()>;
-
-    #[ink(message)]
-    fn change_some_status(&mut self, token_id: Id, number:u32) -> Result<()>;
-
-    // 3 tyepes of uri function
-    // 1) normal uri
-    #[ink(message)]
-    fn set_normal_uri(&mut self, normal_uri: String) -> Result<()>;
-
-    #[ink(message)]
-    fn get_normal_uri(&self) -> String;
-
-    // 2) good uri
-    #[ink(message)]
-    fn set_good_uri(&mut self, good_uri: String) -> Result<()>;
-
-    #[ink(message)]
-    fn get_good_uri(&self) -> String;
-
-    // 3) bad uri
-    #[ink(message)]
-    fn set_bad_uri(&mut self, bad_uri: String) -> Result<()>;
-
-    #[ink(message)]
-    fn get_bad_uri(&self) -> String;
-
-    #[ink(message)]
-    fn get_total_status(&self, token_id: Id) -> u32;
-
-    #[ink(message)]
-    fn get_condition(&self, token_id: Id) -> u32;
-
-    #[ink(message)]
-    fn get_condition_url(&self, token_id: Id) -> String;
-
-    #[ink(message)]
-    fn eat_an_apple(&mut self, token_id: Id, account_id: AccountId) -> Result<()>;
-
-    #[ink(message)]
-    fn token_uri(&self, token_id: Id) -> String;
-
-    #[ink(message)]
-    fn get_your_apple(&self, account_id: AccountId) -> u16;
-
-    #[ink(message)]
-    fn set_your_apple(&mut self, account_id: AccountId, after_money: u16) -> Result<()>;
-
-    #[ink(message)]
-    fn get_your_money(&self, account_id: AccountId) -> u64;
-
-    #[ink(message)]
-    fn set_your_money(&mut self, account_id: AccountId, after_money: u64) -> Result<()>;
-
-    #[ink(message)]
-    fn buy_an_apple(&mut self, account_id: AccountId) -> Result<()>;
-
-    #[ink(message)]
-    fn minus_your_apple(&mut self, account_id: AccountId) -> Result<()>;
-
-    #[ink(message)]
-    fn minus_your_money(&mut self, account_id: AccountId, change_money: u64) -> Result<()>;
-
-    #[ink(message)]
-    fn plus_your_money(&mut self, account_id: AccountId, change_money: u64) -> Result<()>;
-
-    #[ink(message)]
-    fn stake_your_money(&mut self, account_id: AccountId, stake_money: u64) -> Result<()>;
-
-    #[ink(message)]
-    fn get_your_staked_money(&self, account_id: AccountId) -> u64;
-
-    #[ink(message)]
-    fn withdraw_your_money(&mut self, account_id: AccountId) -> Result<()>;
-
-    #[ink(message)]
-    fn daily_bonus(&mut self, account_id: AccountId) -> Result<()>;
-
-    #[ink(message)]
-    fn get_last_eaten(&self, token_id: Id) -> u64;
-
-    #[ink(message)]
-    fn set_last_eaten(&mut self, token_id: Id, current_time: u64) -> Result<()>;
-
-    #[ink(message)]
-    fn get_last_bonus(&self, account_id: AccountId) -> u64;
-
-    #[ink(message)]
-    fn set_last_bonus(&mut self, account_id: AccountId, current_time: u64) -> Result<()>;
-
-    #[ink(message)]
-    fn is_nft_owner(&self, token_id: Id) -> bool;
-
-    #[ink(message)]
-    fn is_account_id(&self, account_id: AccountId) -> bool;
-
-    // #[ink(message)]
-    // fn test2(&mut self, token_id: Id) -> Result<()>;
-
     /// Used to add a asset entry.
     /// The ID of the asset is automatically assigned to be the next available asset ID.
     /// # Arguments
@@ -259,9 +130,6 @@ pub trait MultiAsset {
 
     /// Check that asset id does not already exist.
     fn ensure_asset_id_is_available(&self, asset_id: AssetId) -> Result<()>;
-
-    #[ink(message)]
-    fn test_call_psp22(&mut self, target_account_id:AccountId, to: AccountId, value: Balance, data: Vec<u8>);
 }
 
 /// Trait definitions for MultiAsset ink events
@@ -0,0 +1,144 @@
+//! RMRK PetGame batched player actions
+use crate::{
+    collection::PetCollection,
+    internal::Internal,
+    rate_limit::rate_limited,
+    traits::{
+        Batch,
+        PetGame,
+        Staking,
+    },
+    PetGameData,
+    RateLimitData,
+    StakingData,
+};
+
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    types::*,
+};
+
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    contracts::{
+        access_control::*,
+        psp34::Id,
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+/// Most actions a single batch can run
+pub const MAX_BATCH_SIZE: usize = 20;
+
+/// Trait definitions for Batch helper functions
+pub trait BatchInternal {
+    /// Check that the batch isn't above the size limit
+    fn ensure_batch_size(&self, size: usize) -> Result<()>;
+
+    /// Claim the daily bonus of the account. Return the currency it paid
+    fn _claim_daily_bonus(&mut self, account_id: &AccountId) -> Result<u64>;
+
+    /// Run one action of the caller
+    fn _execute_action(&mut self, caller: &AccountId, action: GameAction) -> Result<ActionResult>;
+}
+
+/// Implement internal helper trait for Batch
+impl<T> BatchInternal for T
+where
+    T: Storage<PetGameData>
+        + Storage<RateLimitData>
+        + Storage<StakingData>
+        + Storage<access_control::Data>
+        + PetCollection,
+{
+    default fn ensure_batch_size(&self, size: usize) -> Result<()> {
+        if size > MAX_BATCH_SIZE {
+            return Err(RmrkError::TooManyActions.into())
+        }
+        Ok(())
+    }
+
+    default fn _claim_daily_bonus(&mut self, account_id: &AccountId) -> Result<u64> {
+        let reward = self._bonus_reward(self._next_bonus_streak(account_id));
+        self.daily_bonus(*account_id)?;
+        Ok(reward.money)
+    }
+
+    default fn _execute_action(
+        &mut self,
+        caller: &AccountId,
+        action: GameAction,
+    ) -> Result<ActionResult> {
+        match action {
+            GameAction::EatAnApple(token_id) => self.eat_an_apple(token_id, *caller)?,
+            GameAction::UseItem(token_id, item_id) => self.use_item(token_id, item_id)?,
+            GameAction::BuyItem(item_id, qty) => self.buy_item(item_id, qty)?,
+            GameAction::Checkpoint(token_id) => self.checkpoint(token_id)?,
+            GameAction::DailyBonus => {
+                return Ok(ActionResult::Claimed(self._claim_daily_bonus(caller)?))
+            }
+            GameAction::ClaimStakingRewards(lock_id) => {
+                return Ok(ActionResult::Claimed(self.claim_staking_rewards(lock_id)?))
+            }
+        }
+        Ok(ActionResult::Done)
+    }
+}
+
+impl<T> Batch for T
+where
+    T: Storage<PetGameData>
+        + Storage<RateLimitData>
+        + Storage<StakingData>
+        + Storage<access_control::Data>
+        + PetCollection,
+{
+    /// Use one item of each pair on its pet, in order.
+    #[modifiers(rate_limited("batch"))]
+    default fn feed_many(&mut self, feedings: Vec<(Id, ItemId)>) -> Result<()> {
+        self.ensure_batch_size(feedings.len())?;
+        for (token_id, item_id) in feedings {
+            self.use_item(token_id, item_id)?;
+        }
+        Ok(())
+    }
+
+    /// Claim the daily bonus if its cooldown is over and the rewards of every stake of the caller.
+    #[modifiers(rate_limited("batch"))]
+    default fn claim_all(&mut self) -> Result<u64> {
+        let caller = Self::env().caller();
+        let mut claimed = 0u64;
+        let subject = CooldownSubject::Account(caller);
+        if self.cooldown_remaining(&Cooldown::DailyBonus, &subject) == 0 {
+            claimed = claimed.saturating_add(self._claim_daily_bonus(&caller)?);
+        }
+
+        let lock_options = self.get_staking_config().lock_options.len() as u32;
+        for lock_id in core::iter::once(None).chain((0..lock_options).map(Some)) {
+            if self.get_pending_staking_rewards(caller, lock_id) > 0 {
+                claimed = claimed.saturating_add(self.claim_staking_rewards(lock_id)?);
+            }
+        }
+        Ok(claimed)
+    }
+
+    /// Run the actions of the caller in order. Return the result of each action.
+    #[modifiers(rate_limited("batch"))]
+    default fn execute_actions(&mut self, actions: Vec<GameAction>) -> Result<Vec<ActionResult>> {
+        self.ensure_batch_size(actions.len())?;
+        let caller = Self::env().caller();
+        let mut results = Vec::with_capacity(actions.len());
+        for action in actions {
+            results.push(self._execute_action(&caller, action)?);
+        }
+        Ok(results)
+    }
+}
@@ -0,0 +1,144 @@
+//! RMRK PetGame collection of the pets
+use rmrk_minting::{
+    internal::Internal as MintingInternal,
+    MintingData,
+};
+use rmrk_multiasset::{
+    traits::MultiAsset,
+    MultiAssetData,
+};
+use rmrk_nesting::{
+    internal::Internal as NestingInternal,
+    traits::NestingEvents,
+    NestingData,
+};
+
+use rmrk_common::{
+    errors::Result,
+    types::*,
+};
+
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    contracts::psp34::extensions::{
+        enumerable::*,
+        metadata::{
+            self,
+            PSP34Metadata,
+        },
+    },
+    traits::{
+        AccountId,
+        Storage,
+        String,
+    },
+};
+
+/// Trait definitions for the RMRK collection holding the pets.
+/// The game keeps its own state and only reaches the pets through it
+pub trait PetCollection {
+    /// Return the owner of the pet. `None` if it doesn't exist
+    fn pet_owner(&self, token_id: &Id) -> Option<AccountId>;
+
+    /// Return the assets accepted by the pet by decreasing priority
+    fn pet_accepted_assets(&self, token_id: &Id) -> Vec<AssetId>;
+
+    /// Return the assets added to the pet its owner didn't accept yet
+    fn pet_pending_assets(&self, token_id: &Id) -> Vec<AssetId>;
+
+    /// Return the uri of the asset entry of the collection. `None` if it doesn't exist
+    fn collection_asset_uri(&self, asset_id: AssetId) -> Option<String>;
+
+    /// Add the asset entry to the pet. It stays pending unless the owner of the pet is the caller
+    fn _add_pet_asset(&mut self, token_id: &Id, asset_id: AssetId) -> Result<()>;
+
+    /// Reorder the accepted assets of the pet. Only done for the owner of the pet.
+    /// Fails with `NotTokenOwner` if the collection doesn't let the game do it for them
+    fn _set_pet_priority(&mut self, token_id: &Id, priorities: Vec<AssetId>) -> Result<()>;
+
+    /// Set an attribute of the pet
+    fn _set_pet_attribute(&mut self, token_id: &Id, key: String, value: String) -> Result<()>;
+
+    /// Mint a new pet to the account. Return its id
+    fn _mint_pet(&mut self, to: AccountId) -> Result<Id>;
+
+    /// Mint a new pet nested into `parent_id`. It stays pending until the owner of the parent
+    /// accepts it. Return its id
+    fn _mint_pet_child(&mut self, parent_id: &Id) -> Result<Id>;
+}
+
+/// Implement the collection for a contract holding both the pets and the game
+impl<T> PetCollection for T
+where
+    T: Storage<psp34::Data<enumerable::Balances>>
+        + Storage<MultiAssetData>
+        + Storage<MintingData>
+        + Storage<NestingData>
+        + Storage<metadata::Data>
+        + PSP34Metadata
+        + psp34::Internal
+        + MultiAsset,
+{
+    default fn pet_owner(&self, token_id: &Id) -> Option<AccountId> {
+        self.data::<psp34::Data<enumerable::Balances>>()
+            .owner_of(token_id.clone())
+    }
+
+    default fn pet_accepted_assets(&self, token_id: &Id) -> Vec<AssetId> {
+        self.data::<MultiAssetData>()
+            .accepted_assets
+            .get(token_id)
+            .unwrap_or_default()
+    }
+
+    default fn pet_pending_assets(&self, token_id: &Id) -> Vec<AssetId> {
+        self.data::<MultiAssetData>()
+            .pending_assets
+            .get(token_id)
+            .unwrap_or_default()
+    }
+
+    default fn collection_asset_uri(&self, asset_id: AssetId) -> Option<String> {
+        self.data::<MultiAssetData>()
+            .collection_asset_entries
+            .get(asset_id)
+            .map(|asset| asset.asset_uri)
+    }
+
+    default fn _add_pet_asset(&mut self, token_id: &Id, asset_id: AssetId) -> Result<()> {
+        self.add_asset_to_token(token_id.clone(), asset_id, None)
+    }
+
+    default fn _set_pet_priority(&mut self, token_id: &Id, priorities: Vec<AssetId>) -> Result<()> {
+        self.set_priority(token_id.clone(), priorities)
+    }
+
+    default fn _set_pet_attribute(
+        &mut self,
+        token_id: &Id,
+        key: String,
+        value: String,
+    ) -> Result<()> {
+        self.data::<metadata::Data>()
+            ._set_attribute(token_id.clone(), key, value);
+        Ok(())
+    }
+
+    default fn _mint_pet(&mut self, to: AccountId) -> Result<Id> {
+        self._check_amount(1)?;
+        self._mint(to)
+    }
+
+    default fn _mint_pet_child(&mut self, parent_id: &Id) -> Result<Id> {
+        let contract = Self::env().account_id();
+        let child_id = self._mint_pet(contract)?;
+
+        // The contract already owns the child, so it's nested without the transfer
+        // `add_child` makes
+        let child_nft = (contract, child_id.clone());
+        self._emit_added_child_event(parent_id, &child_nft.0, &child_nft.1);
+        self.add_to_pending(parent_id.clone(), child_nft);
+        Ok(child_id)
+    }
+}
@@ -0,0 +1,238 @@
+//! RMRK PetGame interactions between pets
+use crate::{
+    collection::PetCollection,
+    internal::Internal,
+    randomness::{
+        roll,
+        RandomnessSource,
+    },
+    rate_limit::rate_limited,
+    traits::{
+        Interactions,
+        PetGame,
+        PetGameEvents,
+    },
+    PetGameData,
+    RateLimitData,
+    DEFAULT_PLAY_EFFECT,
+};
+
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::ADMIN,
+    types::*,
+};
+
+use openbrush::{
+    contracts::{
+        access_control::*,
+        psp34::Id,
+    },
+    modifiers,
+    traits::Storage,
+};
+
+/// Trait definitions for Interactions helper functions
+pub trait InteractionsInternal {
+    /// Check that the pet is at a stage of life allowing the interaction and that
+    /// its cooldown is over
+    fn ensure_can_interact(&self, interaction: PetInteraction, token_id: &Id) -> Result<()>;
+
+    /// Record the consent of the caller to the interaction of its pet with the other pet.
+    /// Return whether both owners agreed, consuming the consent of the other owner.
+    /// A consent given by a previous owner of the other pet is dropped
+    fn _agree(
+        &mut self,
+        interaction: PetInteraction,
+        token_id: &Id,
+        other_token_id: &Id,
+    ) -> Result<bool>;
+
+    /// Draw each stat of the child from one of its parents
+    fn _inherit_status(&mut self, parent_id: &Id, other_parent_id: &Id) -> Result<Status>;
+}
+
+/// Implement internal helper trait for Interactions
+impl<T> InteractionsInternal for T
+where
+    T: Storage<PetGameData> + PetGame + PetCollection,
+{
+    default fn ensure_can_interact(
+        &self,
+        interaction: PetInteraction,
+        token_id: &Id,
+    ) -> Result<()> {
+        let stage = self.lifecycle_stage(token_id);
+        if stage == LifecycleStage::Dead {
+            return Err(RmrkError::PetIsDead.into())
+        }
+        let cooldown = match interaction {
+            PetInteraction::Play => {
+                if stage == LifecycleStage::Egg {
+                    return Err(RmrkError::InvalidLifecycleStage.into())
+                }
+                Cooldown::Play
+            }
+            PetInteraction::Breed => {
+                if stage != LifecycleStage::Adult {
+                    return Err(RmrkError::InvalidLifecycleStage.into())
+                }
+                Cooldown::Breed
+            }
+        };
+        self.ensure_cooldown(&cooldown, &CooldownSubject::Token(token_id.clone()))
+    }
+
+    default fn _agree(
+        &mut self,
+        interaction: PetInteraction,
+        token_id: &Id,
+        other_token_id: &Id,
+    ) -> Result<bool> {
+        let owner = self.ensure_nft_owner(token_id)?;
+        let other_owner = self.ensure_pet_exists(other_token_id)?;
+        if token_id == other_token_id {
+            return Err(RmrkError::InvalidTokenId.into())
+        }
+        self.ensure_can_interact(interaction, token_id)?;
+        self.ensure_can_interact(interaction, other_token_id)?;
+        if owner == other_owner {
+            return Ok(true)
+        }
+
+        let consents = &mut self.data::<PetGameData>().consents;
+        if let Some((consenting, _)) = consents.get((interaction, other_token_id, token_id)) {
+            consents.remove((interaction, other_token_id, token_id));
+            if consenting == other_owner {
+                return Ok(true)
+            }
+        }
+        let current_time = Self::env().block_timestamp();
+        consents.insert(
+            (interaction, token_id, other_token_id),
+            &(owner, current_time),
+        );
+        Ok(false)
+    }
+
+    default fn _inherit_status(&mut self, parent_id: &Id, other_parent_id: &Id) -> Result<Status> {
+        let status = self._checkpoint(parent_id)?;
+        let other_status = self._checkpoint(other_parent_id)?;
+        // One bit of the draw per stat picks the parent it comes from. Commit-reveal seeds
+        // are revealed by feeding, so breeding draws from the block or the VRF instead
+        let draw = match self.get_randomness_kind() {
+            RandomnessKind::CommitReveal { vrf: false } => {
+                roll(&self._hash_randomness(parent_id)?, 8)
+            }
+            RandomnessKind::CommitReveal { vrf: true } => {
+                roll(&self._vrf_randomness(parent_id)?, 8)
+            }
+            _ => self._random(parent_id, 8)?,
+        };
+        let pick = |bit: u32, value: u32, other_value: u32| {
+            if draw & bit == 0 {
+                value
+            } else {
+                other_value
+            }
+        };
+        Ok(Status {
+            hungry: pick(1, status.hungry, other_status.hungry),
+            health: pick(2, status.health, other_status.health),
+            happy: pick(4, status.happy, other_status.happy),
+        })
+    }
+}
+
+impl<T> Interactions for T
+where
+    T: Storage<PetGameData>
+        + Storage<RateLimitData>
+        + Storage<access_control::Data>
+        + PetCollection,
+{
+    /// Play with another pet, which makes both happier.
+    #[modifiers(rate_limited("play"))]
+    default fn play_with(&mut self, token_id: Id, other_token_id: Id) -> Result<bool> {
+        if !self._agree(PetInteraction::Play, &token_id, &other_token_id)? {
+            return Ok(false)
+        }
+
+        let effect = self.get_play_effect();
+        for pet_id in [&token_id, &other_token_id] {
+            self._change_status(pet_id, &effect, StatusCause::Play)?;
+            self._start_cooldown(&Cooldown::Play, &CooldownSubject::Token(pet_id.clone()));
+        }
+        self._emit_pets_played_event(&token_id, &other_token_id);
+        Ok(true)
+    }
+
+    /// Breed two adult pets. The child is minted and nested into `parent_id`.
+    #[modifiers(rate_limited("breed"))]
+    default fn breed(&mut self, parent_id: Id, other_parent_id: Id) -> Result<Option<Id>> {
+        if !self._agree(PetInteraction::Breed, &parent_id, &other_parent_id)? {
+            return Ok(None)
+        }
+
+        let status = self._inherit_status(&parent_id, &other_parent_id)?;
+        // The asset of its stage of life is added to the child once it is born
+        let child_id = self._mint_pet_child(&parent_id)?;
+        self._set_status(&child_id, status, StatusCause::Birth)?;
+
+        for pet_id in [&parent_id, &other_parent_id] {
+            self._start_cooldown(&Cooldown::Breed, &CooldownSubject::Token(pet_id.clone()));
+        }
+        self._emit_pet_bred_event(&parent_id, &other_parent_id, &child_id);
+        Ok(Some(child_id))
+    }
+
+    /// Withdraw the consent of the caller to an interaction with the other pet.
+    #[modifiers(rate_limited("consent"))]
+    default fn withdraw_consent(
+        &mut self,
+        interaction: PetInteraction,
+        token_id: Id,
+        other_token_id: Id,
+    ) -> Result<()> {
+        self.ensure_nft_owner(&token_id)?;
+        self.data::<PetGameData>()
+            .consents
+            .remove((interaction, &token_id, &other_token_id));
+        Ok(())
+    }
+
+    /// Check if the owner of `token_id` agreed to the interaction with the other pet.
+    default fn has_consent(
+        &self,
+        interaction: PetInteraction,
+        token_id: Id,
+        other_token_id: Id,
+    ) -> bool {
+        match self
+            .data::<PetGameData>()
+            .consents
+            .get((interaction, &token_id, &other_token_id))
+        {
+            Some((consenting, _)) => self.pet_owner(&token_id) == Some(consenting),
+            None => false,
+        }
+    }
+
+    /// Set the effect of playing on both pets.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_play_effect(&mut self, effect: StatusDelta) -> Result<()> {
+        self.data::<PetGameData>().play_effect = Some(effect);
+        Ok(())
+    }
+
+    /// Used to retrieve the effect of playing on both pets.
+    default fn get_play_effect(&self) -> StatusDelta {
+        self.data::<PetGameData>()
+            .play_effect
+            .clone()
+            .unwrap_or(DEFAULT_PLAY_EFFECT)
+    }
+}
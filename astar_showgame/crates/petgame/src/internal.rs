@@ -0,0 +1,1049 @@
+use crate::{
+    collection::PetCollection,
+    not_enough_items,
+    randomness::RandomnessSource,
+    traits::{
+        PetGame,
+        PetGameEvents,
+    },
+    PetGameData,
+    DAY,
+    HEALTH_DAYS_KEPT,
+};
+
+use rmrk_common::{
+    errors::{
+        Error,
+        Result,
+        RmrkError,
+    },
+    types::*,
+};
+
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    contracts::{
+        psp34::{
+            Id,
+            PSP34Error,
+        },
+        traits::psp22::{
+            extensions::mintable::PSP22MintableRef,
+            PSP22Error,
+            PSP22Ref,
+        },
+    },
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+
+/// Trait definitions for PetGame helper functions
+pub trait Internal {
+    /// Check that the pet exists. Return its owner
+    fn ensure_pet_exists(&self, token_id: &Id) -> Result<AccountId>;
+
+    /// Check that the caller owns the token. Return the owner
+    fn ensure_nft_owner(&self, token_id: &Id) -> Result<AccountId>;
+
+    /// Check that the caller is `account_id`
+    fn ensure_account_id(&self, account_id: &AccountId) -> Result<()>;
+
+    /// Check that every stat of the status is within the configured bounds
+    fn ensure_stat_bounds(&self, status: &Status) -> Result<()>;
+
+    /// Persist the decayed status of the pet and move its checkpoint forward. Return the status
+    fn _checkpoint(&mut self, token_id: &Id) -> Result<Status>;
+
+    /// Overwrite the stored status of the pet and restart its decay from now
+    fn _set_status(&mut self, token_id: &Id, status: Status, cause: StatusCause) -> Result<()>;
+
+    /// Add the stored status of the pet to its history, dropping the oldest entries once the
+    /// history is full, and record its health
+    fn _record_status(&mut self, token_id: &Id, status: &Status, cause: StatusCause);
+
+    /// Compute the sequence number of the oldest entry kept in the status history of the pet
+    fn status_history_start(&self, token_id: &Id) -> u32;
+
+    /// Return the lowest status history capacity set after `generation`,
+    /// or the current capacity if it wasn't set since
+    fn lowest_status_history_capacity(&self, generation: u32) -> u32;
+
+    /// Lower the health recorded for the pet today to `health` if it is below it.
+    /// Only the last days are kept
+    fn _record_health(&mut self, token_id: &Id, health: u32);
+
+    /// Compute the stage of life of the pet at the current time
+    fn lifecycle_stage(&self, token_id: &Id) -> LifecycleStage;
+
+    /// Persist the stage of life of the pet once its status or age changed it. Return the stage
+    fn _update_lifecycle(&mut self, token_id: &Id) -> Result<LifecycleStage>;
+
+    /// Move the pet to `stage` and render the asset of the stage
+    fn _set_lifecycle_stage(&mut self, token_id: &Id, stage: LifecycleStage) -> Result<()>;
+
+    /// Add the asset of `stage` to the pet and give it the highest priority
+    fn _show_stage_asset(&mut self, token_id: &Id, stage: LifecycleStage) -> Result<()>;
+
+    /// Improve every stat of the pet by `number`, starting from its current status
+    fn _change_some_status(&mut self, token_id: &Id, number: u32) -> Result<()>;
+
+    /// Apply `delta` to the current status of the pet
+    fn _change_status(
+        &mut self,
+        token_id: &Id,
+        delta: &StatusDelta,
+        cause: StatusCause,
+    ) -> Result<()>;
+
+    /// Apply the effect of an outcome to the pet
+    fn _apply_status_effect(
+        &mut self,
+        token_id: &Id,
+        effect: &StatusEffect,
+        cause: StatusCause,
+    ) -> Result<()>;
+
+    /// Draw an outcome from the feeding table. Return its index and the outcome
+    fn _roll_feed_outcome(&mut self, token_id: &Id) -> Result<(u32, FeedOutcome)>;
+
+    /// Set the number of `item_id` owned by the account
+    fn _set_items(&mut self, account_id: &AccountId, item_id: ItemId, amount: u32);
+
+    /// Add `amount` of `item_id` to the inventory of the account
+    fn _add_items(&mut self, account_id: &AccountId, item_id: ItemId, amount: u32) -> Result<()>;
+
+    /// Remove `amount` of `item_id` from the inventory of the account. Return error if it has less
+    fn _remove_items(&mut self, account_id: &AccountId, item_id: ItemId, amount: u32)
+        -> Result<()>;
+
+    /// Use one `item_id` owned by the account on the pet
+    fn _use_item(&mut self, token_id: &Id, account_id: &AccountId, item_id: ItemId) -> Result<()>;
+
+    /// Compute the streak the next daily bonus claim of the account would reach
+    fn _next_bonus_streak(&self, account_id: &AccountId) -> u32;
+
+    /// Return the reward of the daily bonus for the streak day
+    fn _bonus_reward(&self, streak: u32) -> BonusReward;
+
+    /// Rank the pet and its owner on the leaderboards
+    fn _update_rankings(&mut self, token_id: &Id, owner: &AccountId);
+
+    /// Move the entry of `subject` on the leaderboard to match its new score.
+    /// Entries falling out of the top are dropped
+    fn _update_leaderboard(
+        &mut self,
+        kind: LeaderboardKind,
+        subject: LeaderboardSubject,
+        score: u64,
+    );
+
+    /// Return the share of the balances kept by the seasons before `season`
+    fn season_index(&self, season: u32) -> SeasonIndex;
+
+    /// Compute the in-game currency owned by the account in `season`
+    fn season_money(&self, account_id: &AccountId, season: u32) -> u64;
+
+    /// Compute the number of `item_id` owned by the account in `season`
+    fn season_items(&self, account_id: &AccountId, item_id: ItemId, season: u32) -> u32;
+
+    /// Compute the currency staked by the account over all its stakes in `season`
+    fn season_staked_money(&self, account_id: &AccountId, season: u32) -> u64;
+
+    /// Set the currency staked by the account over all its stakes
+    fn _set_staked_money(&mut self, account_id: &AccountId, amount: u64);
+
+    /// Set the in-game currency owned by the account
+    fn _set_your_money(&mut self, account_id: &AccountId, after_money: u64);
+
+    /// Remove `change_money` from the ledger balance of the account.
+    /// Return error if it can't afford it. Ignores the PSP22 currency
+    fn _minus_your_money(&mut self, account_id: &AccountId, change_money: u64) -> Result<()>;
+
+    /// Add `change_money` to the ledger balance of the account.
+    /// Return error if its balance would overflow. Ignores the PSP22 currency
+    fn _plus_your_money(&mut self, account_id: &AccountId, change_money: u64) -> Result<()>;
+
+    /// Take `amount` of currency from the account to the game.
+    /// With a PSP22 currency the account must have approved the game first
+    fn _charge_money(&mut self, account_id: &AccountId, amount: u64) -> Result<()>;
+
+    /// Pay `amount` of currency held by the game to the account
+    fn _pay_money(&mut self, account_id: &AccountId, amount: u64) -> Result<()>;
+
+    /// Reward the account with `amount` of newly minted currency.
+    /// With a PSP22 currency the game must be allowed to mint it
+    fn _reward_money(&mut self, account_id: &AccountId, amount: u64) -> Result<()>;
+
+    /// Compute the status of the pet at the current time.
+    /// Return it along with the time up to which decay has been applied
+    fn decayed_status(&self, token_id: &Id) -> (Status, u64);
+
+    /// Apply `steps` decay steps of the configured decay to the status
+    fn apply_decay(&self, status: &Status, steps: u64) -> Status;
+
+    /// Compute the milliseconds left before the cooldown is over for the token or account
+    fn cooldown_remaining(&self, cooldown: &Cooldown, subject: &CooldownSubject) -> u64;
+
+    /// Check that the cooldown is over for the token or account.
+    /// Return `TimeHasNotPassed` with the milliseconds left otherwise
+    fn ensure_cooldown(&self, cooldown: &Cooldown, subject: &CooldownSubject) -> Result<()>;
+
+    /// Start the cooldown for the token or account from now
+    fn _start_cooldown(&mut self, cooldown: &Cooldown, subject: &CooldownSubject);
+}
+
+/// Implement internal helper trait for PetGame
+impl<T> Internal for T
+where
+    T: Storage<PetGameData> + PetGame + PetCollection,
+{
+    /// Check that the pet exists. Return its owner
+    default fn ensure_pet_exists(&self, token_id: &Id) -> Result<AccountId> {
+        let token_owner = self.pet_owner(token_id).ok_or(PSP34Error::TokenNotExists)?;
+        Ok(token_owner)
+    }
+
+    /// Check that the caller owns the token. Return the owner
+    default fn ensure_nft_owner(&self, token_id: &Id) -> Result<AccountId> {
+        let token_owner = self.ensure_pet_exists(token_id)?;
+        if Self::env().caller() != token_owner {
+            return Err(RmrkError::NotTokenOwner.into())
+        }
+        Ok(token_owner)
+    }
+
+    /// Check that the caller is `account_id`
+    default fn ensure_account_id(&self, account_id: &AccountId) -> Result<()> {
+        if Self::env().caller() != *account_id {
+            return Err(RmrkError::InvalidAccountId.into())
+        }
+        Ok(())
+    }
+
+    /// Check that every stat of the status is within the configured bounds
+    default fn ensure_stat_bounds(&self, status: &Status) -> Result<()> {
+        if !self.data::<PetGameData>().stat_bounds.contains(status) {
+            return Err(RmrkError::StatOutOfRange.into())
+        }
+        Ok(())
+    }
+
+    /// Persist the decayed status of the pet and move its checkpoint forward. Return the status
+    default fn _checkpoint(&mut self, token_id: &Id) -> Result<Status> {
+        let owner = self.ensure_pet_exists(token_id)?;
+        let (status, checkpoint) = self.decayed_status(token_id);
+        if checkpoint == 0 {
+            // Nothing was ever stored for the pet, so its decay starts now
+            let current_time = Self::env().block_timestamp();
+            self.data::<PetGameData>()
+                .last_checkpoint
+                .insert(token_id, &current_time);
+            self._update_lifecycle(token_id)?;
+            self._update_rankings(token_id, &owner);
+            return Ok(status)
+        }
+
+        let stored_status = self.get_status(token_id.clone());
+        let data = self.data::<PetGameData>();
+        data.asset_status.insert(token_id, &status);
+        data.last_checkpoint.insert(token_id, &checkpoint);
+        if stored_status.as_ref() != Some(&status) {
+            self._record_status(token_id, &status, StatusCause::Decay);
+            self._emit_status_changed_event(token_id, &status);
+        } else {
+            self._record_health(token_id, status.health);
+        }
+        self._update_lifecycle(token_id)?;
+        self._update_rankings(token_id, &owner);
+        Ok(status)
+    }
+
+    /// Overwrite the stored status of the pet and restart its decay from now
+    default fn _set_status(
+        &mut self,
+        token_id: &Id,
+        status: Status,
+        cause: StatusCause,
+    ) -> Result<()> {
+        self.ensure_pet_exists(token_id)?;
+        let current_time = Self::env().block_timestamp();
+        let data = self.data::<PetGameData>();
+        let status = data.stat_bounds.clamp_status(&status);
+        data.asset_status.insert(token_id, &status);
+        data.last_checkpoint.insert(token_id, &current_time);
+        self._record_status(token_id, &status, cause);
+        self._emit_status_changed_event(token_id, &status);
+        self._update_lifecycle(token_id)?;
+        Ok(())
+    }
+
+    default fn _record_status(&mut self, token_id: &Id, status: &Status, cause: StatusCause) {
+        self._record_health(token_id, status.health);
+        let capacity = self.get_status_history_capacity();
+        let index = self.get_status_history_len(token_id.clone());
+        let record = StatusRecord {
+            timestamp: Self::env().block_timestamp(),
+            status: status.clone(),
+            cause,
+        };
+        let stored_start = self
+            .data::<PetGameData>()
+            .status_history_start
+            .get(token_id)
+            .map_or(0, |(start, _)| start);
+        let start = self
+            .status_history_start(token_id)
+            .max(index.saturating_add(1).saturating_sub(capacity));
+        let data = self.data::<PetGameData>();
+        data.status_history
+            .insert((token_id.clone(), index), &record);
+        data.status_history_len
+            .insert(token_id, &index.saturating_add(1));
+        // Entries left behind when the capacity was lowered go along with the one that just left
+        // the history. They were kept under a previous capacity, so there are at most
+        // `MAX_STATUS_HISTORY_CAPACITY` of them
+        for dropped in stored_start..start {
+            data.status_history.remove((token_id.clone(), dropped));
+        }
+        data.status_history_start
+            .insert(token_id, &(start, data.status_history_generation));
+    }
+
+    default fn status_history_start(&self, token_id: &Id) -> u32 {
+        let data = self.data::<PetGameData>();
+        let len = data.status_history_len.get(token_id).unwrap_or_default();
+        let (start, generation) = data.status_history_start.get(token_id).unwrap_or_default();
+        // Lowering the capacity drops entries right away, raising it again doesn't bring them back
+        let capacity = self.lowest_status_history_capacity(generation);
+        start.max(len.saturating_sub(capacity))
+    }
+
+    default fn lowest_status_history_capacity(&self, generation: u32) -> u32 {
+        let data = self.data::<PetGameData>();
+        // The first capacity of the stack set after `generation` is the lowest set since
+        let (mut low, mut high) = (0, data.status_history_lows_len);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match data.status_history_lows.get(middle) {
+                Some((set_in, _)) if set_in > generation => high = middle,
+                _ => low = middle + 1,
+            }
+        }
+        match data.status_history_lows.get(low) {
+            Some((_, capacity)) if low < data.status_history_lows_len => capacity,
+            _ => self.get_status_history_capacity(),
+        }
+    }
+
+    default fn _record_health(&mut self, token_id: &Id, health: u32) {
+        let today = Self::env().block_timestamp() / DAY;
+        let data = self.data::<PetGameData>();
+        let mut lows = data.health_lows.get(token_id).unwrap_or_default();
+        match lows.first_mut() {
+            Some((day, low)) if *day == today => *low = (*low).min(health),
+            _ => {
+                lows.insert(0, (today, health));
+                lows.truncate(HEALTH_DAYS_KEPT as usize);
+            }
+        }
+        data.health_lows.insert(token_id, &lows);
+    }
+
+    /// Compute the stage of life of the pet at the current time
+    default fn lifecycle_stage(&self, token_id: &Id) -> LifecycleStage {
+        let data = self.data::<PetGameData>();
+        let stored_stage = data.lifecycle.get(token_id).unwrap_or_default();
+        let born_at = match data.born_at.get(token_id) {
+            None => return stored_stage,
+            Some(born_at) => born_at,
+        };
+        if stored_stage == LifecycleStage::Dead {
+            return stored_stage
+        }
+
+        // Eggs can't starve, every other stage dies once its health is gone
+        let status = self.decayed_status(token_id).0;
+        if stored_stage != LifecycleStage::Egg && status.health == 0 {
+            return LifecycleStage::Dead
+        }
+
+        let config = &data.lifecycle_config;
+        let age = Self::env().block_timestamp().saturating_sub(born_at);
+        let mut stage = stored_stage;
+        if stage == LifecycleStage::Egg && age >= config.hatch_age {
+            stage = LifecycleStage::Baby;
+        }
+        if stage == LifecycleStage::Baby
+            && age >= config.adult_age
+            && self.get_total_status(token_id.clone()) >= config.min_care
+        {
+            stage = LifecycleStage::Adult;
+        }
+        if stage == LifecycleStage::Adult && age >= config.elder_age {
+            stage = LifecycleStage::Elder;
+        }
+        stage
+    }
+
+    /// Persist the stage of life of the pet once its status or age changed it
+    default fn _update_lifecycle(&mut self, token_id: &Id) -> Result<LifecycleStage> {
+        // The life of the pet starts the first time it is looked after
+        let is_born = !self.data::<PetGameData>().born_at.contains(token_id);
+        if is_born {
+            let current_time = Self::env().block_timestamp();
+            self.data::<PetGameData>()
+                .born_at
+                .insert(token_id, &current_time);
+        }
+
+        let stage = self.lifecycle_stage(token_id);
+        let stored_stage = self
+            .data::<PetGameData>()
+            .lifecycle
+            .get(token_id)
+            .unwrap_or_default();
+        if is_born || stage != stored_stage {
+            self._set_lifecycle_stage(token_id, stage)?;
+        }
+        Ok(stage)
+    }
+
+    /// Move the pet to `stage` and render the asset of the stage
+    default fn _set_lifecycle_stage(&mut self, token_id: &Id, stage: LifecycleStage) -> Result<()> {
+        self.data::<PetGameData>()
+            .lifecycle
+            .insert(token_id, &stage);
+        self._emit_lifecycle_changed_event(token_id, stage);
+        self._show_stage_asset(token_id, stage)
+    }
+
+    /// Add the asset of `stage` to the pet and give it the highest priority.
+    /// The asset stays pending until the owner accepts it when someone else moved the pet
+    default fn _show_stage_asset(&mut self, token_id: &Id, stage: LifecycleStage) -> Result<()> {
+        let asset_id = match self.get_stage_asset(stage) {
+            None => return Ok(()),
+            Some(asset_id) => asset_id,
+        };
+        if !self.pet_accepted_assets(token_id).contains(&asset_id)
+            && !self.pet_pending_assets(token_id).contains(&asset_id)
+        {
+            self._add_pet_asset(token_id, asset_id)?;
+        }
+
+        // Only the owner can reorder the assets of the pet
+        let mut priorities = self.pet_accepted_assets(token_id);
+        if !self.is_nft_owner(token_id.clone()) || !priorities.contains(&asset_id) {
+            return Ok(())
+        }
+        priorities.retain(|id| *id != asset_id);
+        priorities.insert(0, asset_id);
+        match self._set_pet_priority(token_id, priorities) {
+            // A collection of its own only lets the owner reorder the assets there
+            Err(Error::Rmrk(RmrkError::NotTokenOwner)) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Improve every stat of the pet by `number`, starting from its current status
+    default fn _change_some_status(&mut self, token_id: &Id, number: u32) -> Result<()> {
+        let original_status = self._checkpoint(token_id)?;
+        // Stats saturate at their bounds instead of wrapping
+        let bounds = self.data::<PetGameData>().stat_bounds;
+        let status = Status {
+            hungry: original_status.hungry.saturating_sub(number),
+            health: bounds.clamp(original_status.health.saturating_add(number)),
+            happy: bounds.clamp(original_status.happy.saturating_add(number)),
+        };
+
+        // The checkpoint already accounts for the elapsed time, so only the status is written
+        self.data::<PetGameData>()
+            .asset_status
+            .insert(token_id, &status);
+        self._record_status(token_id, &status, StatusCause::GameMaster);
+        self._emit_status_changed_event(token_id, &status);
+        self._update_lifecycle(token_id)?;
+        Ok(())
+    }
+
+    /// Apply `delta` to the current status of the pet
+    default fn _change_status(
+        &mut self,
+        token_id: &Id,
+        delta: &StatusDelta,
+        cause: StatusCause,
+    ) -> Result<()> {
+        let bounds = self.data::<PetGameData>().stat_bounds;
+        let status = self._checkpoint(token_id)?.apply(delta, &bounds);
+        self.data::<PetGameData>()
+            .asset_status
+            .insert(token_id, &status);
+        self._record_status(token_id, &status, cause);
+        self._emit_status_changed_event(token_id, &status);
+        self._update_lifecycle(token_id)?;
+        Ok(())
+    }
+
+    /// Apply the effect of an outcome to the pet
+    default fn _apply_status_effect(
+        &mut self,
+        token_id: &Id,
+        effect: &StatusEffect,
+        cause: StatusCause,
+    ) -> Result<()> {
+        match effect {
+            StatusEffect::Change(delta) => self._change_status(token_id, delta, cause),
+            StatusEffect::Set(status) => self._set_status(token_id, status.clone(), cause),
+        }
+    }
+
+    /// Draw an outcome from the feeding table. Return its index and the outcome
+    default fn _roll_feed_outcome(&mut self, token_id: &Id) -> Result<(u32, FeedOutcome)> {
+        let outcomes = self.get_feed_outcomes();
+        // Weights were checked when the table was set, so the sum doesn't overflow
+        let total_weight: u32 = outcomes.iter().map(|outcome| outcome.weight).sum();
+        let roll = self._random(token_id, total_weight)?;
+
+        let mut cumulative_weight = 0;
+        for (index, outcome) in outcomes.into_iter().enumerate() {
+            cumulative_weight += outcome.weight;
+            if roll < cumulative_weight {
+                return Ok((index as u32, outcome))
+            }
+        }
+        Err(RmrkError::BadConfig.into())
+    }
+
+    default fn _set_items(&mut self, account_id: &AccountId, item_id: ItemId, amount: u32) {
+        let data = self.data::<PetGameData>();
+        let season = data.season;
+        let count = data
+            .inventory_season_counts
+            .get((account_id, item_id))
+            .unwrap_or_default();
+        let last_season = count
+            .checked_sub(1)
+            .and_then(|last| data.inventory_seasons.get((account_id, item_id, last)));
+        // The seasons the account skipped are carried over when queried
+        if last_season != Some(season) {
+            data.inventory_seasons
+                .insert((account_id, item_id, count), &season);
+            data.inventory_season_counts
+                .insert((account_id, item_id), &(count + 1));
+        }
+        data.inventory
+            .insert((season, account_id, item_id), &amount);
+    }
+
+    default fn _add_items(
+        &mut self,
+        account_id: &AccountId,
+        item_id: ItemId,
+        amount: u32,
+    ) -> Result<()> {
+        let balance = self
+            .get_item_balance(*account_id, item_id)
+            .checked_add(amount)
+            .ok_or(RmrkError::TooManyItems)?;
+        self._set_items(account_id, item_id, balance);
+        Ok(())
+    }
+
+    default fn _remove_items(
+        &mut self,
+        account_id: &AccountId,
+        item_id: ItemId,
+        amount: u32,
+    ) -> Result<()> {
+        let balance = self
+            .get_item_balance(*account_id, item_id)
+            .checked_sub(amount)
+            .ok_or(not_enough_items(item_id))?;
+        self._set_items(account_id, item_id, balance);
+        Ok(())
+    }
+
+    /// Use one `item_id` owned by the account on the pet
+    default fn _use_item(
+        &mut self,
+        token_id: &Id,
+        account_id: &AccountId,
+        item_id: ItemId,
+    ) -> Result<()> {
+        let item = self.get_item(item_id).ok_or(RmrkError::ItemNotFound)?;
+        let subject = CooldownSubject::Token(token_id.clone());
+        let is_feeding = item.effect == ItemEffect::RollFeedOutcome;
+        self.ensure_cooldown(&Cooldown::Item(item_id), &subject)?;
+        if is_feeding {
+            self.ensure_cooldown(&Cooldown::Feed, &subject)?;
+        }
+        let is_dead = self.lifecycle_stage(token_id) == LifecycleStage::Dead;
+        match item.effect {
+            ItemEffect::Revive(_) if !is_dead => return Err(RmrkError::PetIsNotDead.into()),
+            ItemEffect::Apply(_) | ItemEffect::RollFeedOutcome if is_dead => {
+                return Err(RmrkError::PetIsDead.into())
+            }
+            _ => (),
+        }
+        if self.get_item_balance(*account_id, item_id) == 0 {
+            return Err(not_enough_items(item_id).into())
+        }
+
+        self._checkpoint(token_id)?;
+        let (outcome_index, effect) = match item.effect {
+            ItemEffect::Apply(effect) => (None, effect),
+            ItemEffect::RollFeedOutcome => {
+                let (index, outcome) = self._roll_feed_outcome(token_id)?;
+                (Some(index), outcome.effect)
+            }
+            ItemEffect::Revive(status) => {
+                // The pet starts its life again as a baby and grows up from its new status
+                let current_time = Self::env().block_timestamp();
+                self.data::<PetGameData>()
+                    .born_at
+                    .insert(token_id, &current_time);
+                self._set_lifecycle_stage(token_id, LifecycleStage::Baby)?;
+                (None, StatusEffect::Set(status))
+            }
+        };
+        self._remove_items(account_id, item_id, 1)?;
+        self._start_cooldown(&Cooldown::Item(item_id), &subject);
+        if is_feeding {
+            self._start_cooldown(&Cooldown::Feed, &subject);
+            let feed_count = self.get_feed_count(*account_id).saturating_add(1);
+            self.data::<PetGameData>()
+                .feed_counts
+                .insert(account_id, &feed_count);
+        }
+        let current_time = Self::env().block_timestamp();
+        self.data::<PetGameData>()
+            .last_eaten
+            .insert(token_id, &current_time);
+
+        self._apply_status_effect(token_id, &effect, StatusCause::Item(item_id))?;
+        if let Some(index) = outcome_index {
+            self._emit_feed_outcome_event(token_id, index, &effect);
+        }
+        self._emit_item_used_event(token_id, account_id, item_id);
+        Ok(())
+    }
+
+    default fn _next_bonus_streak(&self, account_id: &AccountId) -> u32 {
+        let data = self.data::<PetGameData>();
+        let last_bonus = match data
+            .last_used
+            .get((Cooldown::DailyBonus, CooldownSubject::Account(*account_id)))
+        {
+            None => return 1,
+            Some(last_bonus) => last_bonus,
+        };
+
+        let deadline = last_bonus
+            .saturating_add(self.get_cooldown(Cooldown::DailyBonus))
+            .saturating_add(data.bonus_config.claim_window);
+        if Self::env().block_timestamp() > deadline {
+            return 1
+        }
+        data.bonus_streaks
+            .get(account_id)
+            .unwrap_or_default()
+            .saturating_add(1)
+    }
+
+    default fn _bonus_reward(&self, streak: u32) -> BonusReward {
+        let rewards = &self.data::<PetGameData>().bonus_config.rewards;
+        let day = (streak.max(1) as usize).min(rewards.len());
+        rewards
+            .get(day.saturating_sub(1))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    default fn _update_rankings(&mut self, token_id: &Id, owner: &AccountId) {
+        let total_status = self.get_total_status(token_id.clone());
+        self._update_leaderboard(
+            LeaderboardKind::PetStatus,
+            LeaderboardSubject::Pet(token_id.clone()),
+            total_status as u64,
+        );
+        let wealth = self
+            .get_your_money(*owner)
+            .saturating_add(self.get_staked_money(*owner));
+        self._update_leaderboard(
+            LeaderboardKind::Wealth,
+            LeaderboardSubject::Player(*owner),
+            wealth,
+        );
+        let streak = self.get_bonus_streak(*owner);
+        self._update_leaderboard(
+            LeaderboardKind::BonusStreak,
+            LeaderboardSubject::Player(*owner),
+            streak as u64,
+        );
+    }
+
+    default fn _update_leaderboard(
+        &mut self,
+        kind: LeaderboardKind,
+        subject: LeaderboardSubject,
+        score: u64,
+    ) {
+        let size = self.get_leaderboard_size() as usize;
+        let mut entries = self
+            .data::<PetGameData>()
+            .leaderboards
+            .get(kind)
+            .unwrap_or_default();
+        entries.retain(|entry| entry.subject != subject);
+
+        // Entries keep their rank against later ones with the same score
+        let rank = entries
+            .iter()
+            .position(|entry| entry.score < score)
+            .unwrap_or(entries.len());
+        if score > 0 && rank < size {
+            entries.insert(rank, LeaderboardEntry { subject, score });
+        }
+        entries.truncate(size);
+        self.data::<PetGameData>()
+            .leaderboards
+            .insert(kind, &entries);
+    }
+
+    default fn season_index(&self, season: u32) -> SeasonIndex {
+        self.data::<PetGameData>()
+            .season_indexes
+            .get(season)
+            .unwrap_or_default()
+    }
+
+    default fn season_money(&self, account_id: &AccountId, season: u32) -> u64 {
+        let data = self.data::<PetGameData>();
+        let count = data.money_season_counts.get(account_id).unwrap_or_default();
+        let stored = stored_season(count, season, |n| {
+            data.money_seasons.get((account_id, n)).unwrap_or_default()
+        });
+        match stored {
+            None => 0,
+            Some(stored) => {
+                let money = data
+                    .your_money
+                    .get((stored, account_id))
+                    .unwrap_or_default();
+                carried(
+                    money as u128,
+                    &self.season_index(stored).money,
+                    &self.season_index(season).money,
+                    |epoch| data.money_epoch_ends.get(epoch).unwrap_or_default(),
+                ) as u64
+            }
+        }
+    }
+
+    default fn season_items(&self, account_id: &AccountId, item_id: ItemId, season: u32) -> u32 {
+        let data = self.data::<PetGameData>();
+        let count = data
+            .inventory_season_counts
+            .get((account_id, item_id))
+            .unwrap_or_default();
+        let stored = stored_season(count, season, |n| {
+            data.inventory_seasons
+                .get((account_id, item_id, n))
+                .unwrap_or_default()
+        });
+        match stored {
+            None => 0,
+            Some(stored) => {
+                let items = data
+                    .inventory
+                    .get((stored, account_id, item_id))
+                    .unwrap_or_default();
+                carried(
+                    items as u128,
+                    &self.season_index(stored).items,
+                    &self.season_index(season).items,
+                    |epoch| data.items_epoch_ends.get(epoch).unwrap_or_default(),
+                ) as u32
+            }
+        }
+    }
+
+    default fn season_staked_money(&self, account_id: &AccountId, season: u32) -> u64 {
+        let data = self.data::<PetGameData>();
+        let count = data
+            .staked_season_counts
+            .get(account_id)
+            .unwrap_or_default();
+        let stored = stored_season(count, season, |n| {
+            data.staked_seasons.get((account_id, n)).unwrap_or_default()
+        });
+        match stored {
+            None => 0,
+            // Stakes are kept whole unless a season in between reset them
+            Some(stored)
+                if self.season_index(stored).stake_resets
+                    == self.season_index(season).stake_resets =>
+            {
+                data.staked_money
+                    .get((stored, account_id))
+                    .unwrap_or_default()
+            }
+            Some(_) => 0,
+        }
+    }
+
+    default fn _set_staked_money(&mut self, account_id: &AccountId, amount: u64) {
+        let data = self.data::<PetGameData>();
+        let season = data.season;
+        let count = data
+            .staked_season_counts
+            .get(account_id)
+            .unwrap_or_default();
+        let last_season = count
+            .checked_sub(1)
+            .and_then(|last| data.staked_seasons.get((account_id, last)));
+        if last_season != Some(season) {
+            data.staked_seasons.insert((account_id, count), &season);
+            data.staked_season_counts.insert(account_id, &(count + 1));
+        }
+        data.staked_money.insert((season, account_id), &amount);
+    }
+
+    default fn _set_your_money(&mut self, account_id: &AccountId, after_money: u64) {
+        let data = self.data::<PetGameData>();
+        let season = data.season;
+        let count = data.money_season_counts.get(account_id).unwrap_or_default();
+        let last_season = count
+            .checked_sub(1)
+            .and_then(|last| data.money_seasons.get((account_id, last)));
+        if last_season != Some(season) {
+            data.money_seasons.insert((account_id, count), &season);
+            data.money_season_counts.insert(account_id, &(count + 1));
+        }
+        data.your_money.insert((season, account_id), &after_money);
+    }
+
+    default fn _minus_your_money(
+        &mut self,
+        account_id: &AccountId,
+        change_money: u64,
+    ) -> Result<()> {
+        let season = self.data::<PetGameData>().season;
+        let money = self.season_money(account_id, season);
+        if money < change_money {
+            return Err(RmrkError::NotEnoughMoney.into())
+        }
+        self._set_your_money(account_id, money - change_money);
+        Ok(())
+    }
+
+    default fn _plus_your_money(
+        &mut self,
+        account_id: &AccountId,
+        change_money: u64,
+    ) -> Result<()> {
+        let season = self.data::<PetGameData>().season;
+        let money = self
+            .season_money(account_id, season)
+            .checked_add(change_money)
+            .ok_or(RmrkError::MoneyOverflow)?;
+        self._set_your_money(account_id, money);
+        Ok(())
+    }
+
+    default fn _charge_money(&mut self, account_id: &AccountId, amount: u64) -> Result<()> {
+        match self.get_currency() {
+            None => self._minus_your_money(account_id, amount),
+            Some(currency) => {
+                PSP22Ref::transfer_from(
+                    &currency,
+                    *account_id,
+                    Self::env().account_id(),
+                    amount as Balance,
+                    Vec::new(),
+                )
+                .map_err(currency_error)
+            }
+        }
+    }
+
+    default fn _pay_money(&mut self, account_id: &AccountId, amount: u64) -> Result<()> {
+        match self.get_currency() {
+            None => self._plus_your_money(account_id, amount),
+            Some(currency) => {
+                PSP22Ref::transfer(&currency, *account_id, amount as Balance, Vec::new())
+                    .map_err(currency_error)
+            }
+        }
+    }
+
+    default fn _reward_money(&mut self, account_id: &AccountId, amount: u64) -> Result<()> {
+        match self.get_currency() {
+            None => self._plus_your_money(account_id, amount),
+            Some(currency) => {
+                PSP22MintableRef::mint(&currency, *account_id, amount as Balance)
+                    .map_err(currency_error)
+            }
+        }
+    }
+
+    /// Compute the status of the pet at the current time.
+    /// Return it along with the time up to which decay has been applied
+    default fn decayed_status(&self, token_id: &Id) -> (Status, u64) {
+        let data = self.data::<PetGameData>();
+        // Stats stored before the maximum was lowered are clamped when read
+        let status = data
+            .stat_bounds
+            .clamp_status(&data.asset_status.get(token_id).unwrap_or(Status {
+                hungry: 0,
+                health: 0,
+                happy: 0,
+            }));
+        let last_eaten = data.last_eaten.get(token_id).unwrap_or_default();
+        // Pets fed before checkpoints existed decay from their last meal
+        let checkpoint = data.last_checkpoint.get(token_id).unwrap_or(last_eaten);
+        if checkpoint == 0 {
+            return (status, 0)
+        }
+
+        let config = &data.decay_config;
+        let decay_start = checkpoint.max(last_eaten.saturating_add(config.grace_period));
+        let steps = Self::env()
+            .block_timestamp()
+            .saturating_sub(decay_start)
+            .checked_div(config.time_unit)
+            .unwrap_or_default();
+        if steps == 0 {
+            return (status, checkpoint)
+        }
+
+        // Only whole steps move the checkpoint, so checkpointing more often never changes the result
+        let checkpoint = decay_start.saturating_add(steps.saturating_mul(config.time_unit));
+        (self.apply_decay(&status, steps), checkpoint)
+    }
+
+    /// Apply `steps` decay steps of the configured decay to the status
+    default fn apply_decay(&self, status: &Status, steps: u64) -> Status {
+        let config = &self.data::<PetGameData>().decay_config;
+        let decay =
+            |rate: u32| u32::try_from(steps.saturating_mul(rate as u64)).unwrap_or(u32::MAX);
+
+        // Decay never moves a stat past its cap or floor, but keeps values already beyond it
+        let hungry = if status.hungry < config.hungry_cap {
+            status
+                .hungry
+                .saturating_add(decay(config.hungry_rate))
+                .min(config.hungry_cap)
+        } else {
+            status.hungry
+        };
+        let health = if status.health > config.health_floor {
+            status
+                .health
+                .saturating_sub(decay(config.health_rate))
+                .max(config.health_floor)
+        } else {
+            status.health
+        };
+        let happy = if status.happy > config.happy_floor {
+            status
+                .happy
+                .saturating_sub(decay(config.happy_rate))
+                .max(config.happy_floor)
+        } else {
+            status.happy
+        };
+
+        // Hunger can't grow past the maximum stat even with a higher cap
+        Status {
+            hungry: self.data::<PetGameData>().stat_bounds.clamp(hungry),
+            health,
+            happy,
+        }
+    }
+
+    default fn cooldown_remaining(&self, cooldown: &Cooldown, subject: &CooldownSubject) -> u64 {
+        // A cooldown that never started is over
+        match self
+            .data::<PetGameData>()
+            .last_used
+            .get((cooldown, subject))
+        {
+            None => 0,
+            Some(last_used) => {
+                last_used
+                    .saturating_add(self.get_cooldown(*cooldown))
+                    .saturating_sub(Self::env().block_timestamp())
+            }
+        }
+    }
+
+    default fn ensure_cooldown(
+        &self,
+        cooldown: &Cooldown,
+        subject: &CooldownSubject,
+    ) -> Result<()> {
+        match self.cooldown_remaining(cooldown, subject) {
+            0 => Ok(()),
+            remaining => Err(RmrkError::TimeHasNotPassed(remaining).into()),
+        }
+    }
+
+    default fn _start_cooldown(&mut self, cooldown: &Cooldown, subject: &CooldownSubject) {
+        let current_time = Self::env().block_timestamp();
+        self.data::<PetGameData>()
+            .last_used
+            .insert((cooldown, subject), &current_time);
+    }
+}
+
+/// Return the last of the `count` seasons returned by `season_at` in increasing order
+/// that isn't after `season`
+pub fn stored_season(count: u32, season: u32, season_at: impl Fn(u32) -> u32) -> Option<u32> {
+    let last = season_at(count.checked_sub(1)?);
+    if last <= season {
+        return Some(last)
+    }
+    // Only the queries of past seasons search the older ones
+    let (mut low, mut high) = (0, count - 1);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if season_at(middle) <= season {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    low.checked_sub(1).map(season_at)
+}
+
+/// Apply to `amount` the share kept between the indexes `from` and `to` of a later season.
+/// `epoch_end` returns the factor an epoch ended with
+pub fn carried(
+    amount: u128,
+    from: &CarryIndex,
+    to: &CarryIndex,
+    epoch_end: impl Fn(u32) -> u128,
+) -> u128 {
+    let (mut amount, mut factor, mut epoch) = (amount, from.factor, from.epoch);
+    // A whole epoch keeps less than `MIN_CARRY_FACTOR / CARRY_PRECISION` of a balance,
+    // so this stops after a few epochs at most
+    while epoch < to.epoch && amount > 0 {
+        amount = amount * epoch_end(epoch) / factor;
+        factor = CARRY_PRECISION;
+        epoch += 1;
+    }
+    amount * to.factor / factor
+}
+
+/// Map errors of the PSP22 currency to the game errors
+pub fn currency_error(error: PSP22Error) -> Error {
+    match error {
+        PSP22Error::InsufficientBalance => RmrkError::NotEnoughMoney.into(),
+        PSP22Error::InsufficientAllowance => RmrkError::CurrencyNotApproved.into(),
+        _ => RmrkError::CurrencyCallFailed.into(),
+    }
+}
@@ -0,0 +1,1410 @@
+//! RMRK PetGame implementation
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+#![allow(clippy::inline_fn_without_body)]
+
+pub mod batch;
+pub mod collection;
+pub mod interactions;
+pub mod internal;
+pub mod quests;
+pub mod randomness;
+pub mod rate_limit;
+pub mod seasons;
+pub mod shop;
+pub mod staking;
+pub mod traits;
+
+use collection::PetCollection;
+use internal::Internal;
+use randomness::RandomnessSource;
+use rate_limit::rate_limited;
+
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::{
+        ADMIN,
+        CONTRIBUTOR,
+        GAME_MASTER,
+    },
+    types::*,
+};
+
+use traits::{
+    PetGame,
+    PetGameEvents,
+};
+
+use ink::{
+    prelude::{
+        format,
+        string::ToString,
+        vec,
+        vec::Vec,
+    },
+    storage::Mapping,
+};
+
+use openbrush::{
+    contracts::{
+        access_control::*,
+        psp34::Id,
+        traits::psp22::PSP22Ref,
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Hash,
+        Storage,
+        String,
+    },
+};
+
+/// Status of a fully fed pet
+pub const FULL_STATUS: Status = Status {
+    hungry: 0,
+    health: 100,
+    happy: 100,
+};
+
+/// Status of a pet that ate a bad apple
+pub const DEATH_STATUS: Status = Status {
+    hungry: 80,
+    health: 0,
+    happy: 0,
+};
+
+/// Item id of the apple, which is always registered
+pub const APPLE_ITEM_ID: ItemId = 0;
+
+/// Apple used until an admin updates it
+pub fn default_apple() -> Item {
+    Item {
+        name: String::from("Apple"),
+        price: 20,
+        effect: ItemEffect::RollFeedOutcome,
+        cooldown: 0,
+    }
+}
+
+/// Error returned when an account doesn't have enough of `item_id`.
+/// Apples keep the error they had before the item registry
+pub fn not_enough_items(item_id: ItemId) -> RmrkError {
+    match item_id {
+        APPLE_ITEM_ID => RmrkError::NotEnoughApple,
+        _ => RmrkError::NotEnoughItems,
+    }
+}
+
+/// Duration in milliseconds of a cooldown until an admin sets it
+pub fn default_cooldown(cooldown: Cooldown) -> u64 {
+    match cooldown {
+        Cooldown::Feed => 60 * 1000,
+        Cooldown::DailyBonus => 60 * 1000,
+        Cooldown::Play => 5 * 60 * 1000,
+        Cooldown::Breed => 24 * 60 * 60 * 1000,
+        Cooldown::Item(_) => 0,
+    }
+}
+
+/// Effect of playing on both pets until an admin sets it
+pub const DEFAULT_PLAY_EFFECT: StatusDelta = StatusDelta {
+    hungry: 10,
+    health: 0,
+    happy: 20,
+};
+
+/// Outcome table used for feeding until an admin sets one.
+/// Dead pets stay dead until a revival item is used, and none is registered by default,
+/// so the bad apple upsets the pet instead of killing it
+pub fn default_feed_outcomes() -> Vec<FeedOutcome> {
+    vec![
+        FeedOutcome {
+            weight: 25,
+            effect: StatusEffect::Change(StatusDelta {
+                hungry: -30,
+                health: 30,
+                happy: 30,
+            }),
+        },
+        FeedOutcome {
+            weight: 25,
+            effect: StatusEffect::Set(FULL_STATUS),
+        },
+        FeedOutcome {
+            weight: 25,
+            effect: StatusEffect::Change(StatusDelta {
+                hungry: -50,
+                health: 50,
+                happy: 50,
+            }),
+        },
+        FeedOutcome {
+            weight: 26,
+            effect: StatusEffect::Change(StatusDelta {
+                hungry: 10,
+                health: 0,
+                happy: -30,
+            }),
+        },
+    ]
+}
+
+/// Number of entries kept on each leaderboard until an admin sets it
+pub const DEFAULT_LEADERBOARD_SIZE: u32 = 10;
+
+/// Largest number of entries an admin can keep on each leaderboard
+pub const MAX_LEADERBOARD_SIZE: u32 = 100;
+
+/// Leaderboards kept by the game
+pub const LEADERBOARD_KINDS: [LeaderboardKind; 3] = [
+    LeaderboardKind::PetStatus,
+    LeaderboardKind::Wealth,
+    LeaderboardKind::BonusStreak,
+];
+
+/// Number of entries kept in the status history of each pet until an admin sets it
+pub const DEFAULT_STATUS_HISTORY_CAPACITY: u32 = 50;
+
+/// Largest number of entries an admin can keep in the status history of each pet
+pub const MAX_STATUS_HISTORY_CAPACITY: u32 = 500;
+
+/// Length of a day in milliseconds
+pub const DAY: u64 = 24 * 60 * 60 * 1000;
+
+/// Number of days the lowest health of each pet is kept for
+pub const HEALTH_DAYS_KEPT: u32 = 30;
+
+/// Format the token id for uris. Byte ids which aren't UTF-8 are written in hex
+pub fn id_to_bytes(token_id: &Id) -> Vec<u8> {
+    let id_string = match token_id {
+        Id::U8(id) => id.to_string(),
+        Id::U16(id) => id.to_string(),
+        Id::U32(id) => id.to_string(),
+        Id::U64(id) => id.to_string(),
+        Id::U128(id) => id.to_string(),
+        Id::Bytes(value) => {
+            match core::str::from_utf8(value) {
+                Ok(id) => id.to_string(),
+                Err(_) => value.iter().map(|byte| format!("{:02x}", byte)).collect(),
+            }
+        }
+    };
+    id_string.into_bytes()
+}
+
+/// Number the entries of a leaderboard by rank and return the page starting at `offset`
+fn rank_entries(
+    entries: Vec<LeaderboardEntry>,
+    offset: u32,
+    limit: u32,
+) -> Vec<(u32, LeaderboardEntry)> {
+    entries
+        .into_iter()
+        .enumerate()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .map(|(index, entry)| (index as u32 + 1, entry))
+        .collect()
+}
+
+pub const STORAGE_PETGAME_KEY: u32 = openbrush::storage_unique_key!(PetGameData);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_PETGAME_KEY)]
+pub struct PetGameData {
+    /// Mapping of tokenId to the stored status of the pet
+    pub asset_status: Mapping<Id, Status>,
+
+    /// Base uris used for each condition of the pet
+    pub normal_uri: String,
+    pub good_uri: String,
+    pub bad_uri: String,
+
+    /// Salt used for the pseudo random generator
+    pub salt: u64,
+
+    /// Mapping of tokenId to the last time the pet was fed
+    pub last_eaten: Mapping<Id, u64>,
+
+    /// Mapping of item id to the registered item
+    pub items: Mapping<ItemId, Item>,
+
+    /// Id of the last registered item
+    pub last_item_id: ItemId,
+
+    /// Mapping of (season, account, item id) to the number of items owned in the season,
+    /// stored in the seasons they changed in
+    pub inventory: Mapping<(u32, AccountId, ItemId), u32>,
+
+    /// Mapping of (account, item id, n) to the n-th season the number of items was stored in
+    pub inventory_seasons: Mapping<(AccountId, ItemId, u32), u32>,
+
+    /// Mapping of (account, item id) to the number of seasons the number of items was stored in
+    pub inventory_season_counts: Mapping<(AccountId, ItemId), u32>,
+
+    /// Mapping of cooldown to its duration set by an admin
+    pub cooldown_durations: Mapping<Cooldown, u64>,
+
+    /// Mapping of (cooldown, token or account) to the last time the cooldown started
+    pub last_used: Mapping<(Cooldown, CooldownSubject), u64>,
+
+    /// Rewards of the daily bonus streak and its claim window
+    pub bonus_config: BonusConfig,
+
+    /// Mapping of account to the number of consecutive daily bonus claims
+    pub bonus_streaks: Mapping<AccountId, u32>,
+
+    /// Mapping of (season, account) to the currency it staked in the season, over all its stakes,
+    /// stored in the seasons it changed in
+    pub staked_money: Mapping<(u32, AccountId), u64>,
+
+    /// Mapping of (account, n) to the n-th season its staked currency was stored in
+    pub staked_seasons: Mapping<(AccountId, u32), u32>,
+
+    /// Mapping of account to the number of seasons its staked currency was stored in
+    pub staked_season_counts: Mapping<AccountId, u32>,
+
+    /// Mapping of (season, account) to the in-game currency owned in the season,
+    /// stored in the seasons it changed in. Used while no PSP22 currency is set
+    pub your_money: Mapping<(u32, AccountId), u64>,
+
+    /// Mapping of (account, n) to the n-th season its in-game currency was stored in
+    pub money_seasons: Mapping<(AccountId, u32), u32>,
+
+    /// Mapping of account to the number of seasons its in-game currency was stored in
+    pub money_season_counts: Mapping<AccountId, u32>,
+
+    /// PSP22 contract used as the in-game currency
+    pub currency: Option<AccountId>,
+
+    /// How the status of the pets decays over time
+    pub decay_config: DecayConfig,
+
+    /// Range the stats of the pets are kept in
+    pub stat_bounds: StatBounds,
+
+    /// Mapping of tokenId to the time up to which decay is included in the stored status
+    pub last_checkpoint: Mapping<Id, u64>,
+
+    /// Where the outcomes of feeding are drawn from
+    pub randomness_kind: RandomnessKind,
+
+    /// Mapping of tokenId to the committed secret hash and the block it was committed in
+    pub commitments: Mapping<Id, (Hash, u32)>,
+
+    /// Mapping of tokenId to the seed revealed for its feeding, only kept during the message
+    /// revealing it
+    pub revealed_seeds: Mapping<Id, [u8; 32]>,
+
+    /// Weighted outcomes of feeding. Empty until an admin sets them
+    pub feed_outcomes: Vec<FeedOutcome>,
+
+    /// When the pets move to their next stage of life
+    pub lifecycle_config: LifecycleConfig,
+
+    /// Mapping of tokenId to the stored stage of life of the pet
+    pub lifecycle: Mapping<Id, LifecycleStage>,
+
+    /// Mapping of tokenId to the time the pet was first looked after
+    pub born_at: Mapping<Id, u64>,
+
+    /// Mapping of stage of life to the asset rendered for pets in it
+    pub stage_assets: Mapping<LifecycleStage, AssetId>,
+
+    /// Tiers of total status mapped to collection assets, by increasing threshold
+    pub condition_tiers: Vec<ConditionTier>,
+
+    /// Effect of playing on both pets. `None` until an admin sets it
+    pub play_effect: Option<StatusDelta>,
+
+    /// Mapping of (interaction, tokenId, other tokenId) to the owner of the pet who agreed
+    /// to the interaction with the other pet and the time they agreed
+    pub consents: Mapping<(PetInteraction, Id, Id), (AccountId, u64)>,
+
+    /// Mapping of leaderboard to its entries by decreasing score
+    pub leaderboards: Mapping<LeaderboardKind, Vec<LeaderboardEntry>>,
+
+    /// Number of entries kept on each leaderboard. 0 until an admin sets it
+    pub leaderboard_size: u32,
+
+    /// Season of the current leaderboards, increased each time they are reset
+    pub leaderboard_season: u32,
+
+    /// Mapping of (season, leaderboard) to the entries it had when the season ended
+    pub leaderboard_snapshots: Mapping<(u32, LeaderboardKind), Vec<LeaderboardEntry>>,
+
+    /// Mapping of account to the number of times it fed its pets
+    pub feed_counts: Mapping<AccountId, u32>,
+
+    /// Mapping of tokenId to the lowest health of the pet on each day its status was updated,
+    /// most recent day first
+    pub health_lows: Mapping<Id, Vec<(u64, u32)>>,
+
+    /// Mapping of (tokenId, sequence number) to the entries of the status history of the pet.
+    /// The oldest entry is dropped once the history is full
+    pub status_history: Mapping<(Id, u32), StatusRecord>,
+
+    /// Mapping of tokenId to the number of entries ever added to the status history of the pet
+    pub status_history_len: Mapping<Id, u32>,
+
+    /// Number of entries kept in the status history of each pet. 0 until an admin sets it
+    pub status_history_capacity: u32,
+
+    /// Mapping of tokenId to the sequence number of the oldest entry still stored in the status
+    /// history of the pet and the capacity generation it was stored in
+    pub status_history_start: Mapping<Id, (u32, u32)>,
+
+    /// Number of times the status history capacity was set
+    pub status_history_generation: u32,
+
+    /// Stack of the (generation, capacity) pairs of the capacities lower than every capacity set
+    /// after them. Both increase from the bottom of the stack
+    pub status_history_lows: Mapping<u32, (u32, u32)>,
+
+    /// Number of entries in `status_history_lows`
+    pub status_history_lows_len: u32,
+
+    /// Current season of the game economy, increased each time a new one starts
+    pub season: u32,
+
+    /// Mapping of season to the share of the balances kept when it ended
+    pub carry_overs: Mapping<u32, CarryOver>,
+
+    /// Mapping of season to the share of the balances kept by the seasons before it
+    pub season_indexes: Mapping<u32, SeasonIndex>,
+
+    /// Mapping of epoch to the factor of the in-game currency index when the epoch ended
+    pub money_epoch_ends: Mapping<u32, u128>,
+
+    /// Mapping of epoch to the factor of the items index when the epoch ended
+    pub items_epoch_ends: Mapping<u32, u128>,
+
+    /// Mapping of n to the season that reset the stakes for the n-th time
+    pub stake_reset_seasons: Mapping<u32, u32>,
+}
+
+pub const STORAGE_STAKING_KEY: u32 = openbrush::storage_unique_key!(StakingData);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_STAKING_KEY)]
+pub struct StakingData {
+    /// APR and lock options of staking
+    pub config: StakingConfig,
+
+    /// Mapping of (account, lock option) to the stake
+    pub positions: Mapping<(AccountId, Option<u32>), StakePosition>,
+
+    /// Currency staked by all accounts
+    pub total_staked: u64,
+
+    /// Sum of the staked currency multiplied by its boost
+    pub total_weight: u128,
+
+    /// Rewards accumulated per unit of weight, scaled by `REWARD_PRECISION`
+    pub reward_per_weight: u128,
+
+    /// Last time the rewards were accumulated
+    pub last_update: u64,
+
+    /// Currency left to pay future rewards
+    pub treasury: u64,
+
+    /// Mapping of season to the accumulated reward per weight when it ended
+    pub season_rewards: Mapping<u32, u128>,
+}
+
+pub const STORAGE_SHOP_KEY: u32 = openbrush::storage_unique_key!(ShopData);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_SHOP_KEY)]
+pub struct ShopData {
+    /// Mapping of listing id to the listing
+    pub listings: Mapping<ListingId, Listing>,
+
+    /// Id of the last listing added
+    pub last_listing_id: ListingId,
+
+    /// Mapping of (listing, account) to the number of items the account bought from it
+    pub purchased: Mapping<(ListingId, AccountId), u32>,
+
+    /// Mapping of currency to the revenue not withdrawn yet
+    pub revenue: Mapping<ShopCurrency, Balance>,
+}
+
+pub const STORAGE_QUEST_KEY: u32 = openbrush::storage_unique_key!(QuestData);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_QUEST_KEY)]
+pub struct QuestData {
+    /// Mapping of quest id to the registered quest
+    pub quests: Mapping<QuestId, Quest>,
+
+    /// Id of the last registered quest
+    pub last_quest_id: QuestId,
+
+    /// Mapping of (quest, account) to the time the account claimed the quest
+    pub claimed_at: Mapping<(QuestId, AccountId), u64>,
+
+    /// Mapping of (quest, pet) to the time the quest was claimed with the pet,
+    /// for quests reached by pets
+    pub pet_claimed_at: Mapping<(QuestId, Id), u64>,
+}
+
+pub const STORAGE_RATE_LIMIT_KEY: u32 = openbrush::storage_unique_key!(RateLimitData);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_RATE_LIMIT_KEY)]
+pub struct RateLimitData {
+    /// Mapping of action to the budget of each account for it
+    pub budgets: Mapping<String, ActionBudget>,
+
+    /// Mapping of (action, account) to the start of its current window and the calls it made in it
+    pub usage: Mapping<(String, AccountId), (u64, u32)>,
+
+    /// Most rate limited calls all accounts can make in one block. `None` is unlimited
+    pub block_cap: Option<u32>,
+
+    /// Last block a rate limited call was made in and the number of calls made in it
+    pub block_usage: (u32, u32),
+}
+
+impl<T> PetGame for T
+where
+    T: Storage<PetGameData>
+        + Storage<RateLimitData>
+        + Storage<access_control::Data>
+        + PetCollection,
+{
+    /// Set the default condition uris and the starting items for `account_id`.
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn set_default(&mut self, account_id: AccountId) -> Result<()> {
+        self.data::<PetGameData>().bad_uri =
+            String::from("ipfs://QmYJhYes1kzp2soWYEYKzvA84V8YivL8BCpsnN773xyufr/");
+        self.data::<PetGameData>().normal_uri =
+            String::from("ipfs://QmXtnr9aEJVywiLs1keZdyiKbQwignZT3FhwKYivF15oZp/");
+        self.data::<PetGameData>().good_uri =
+            String::from("ipfs://QmZAdpKf4zr9x2vX26gU6LkG8gtj44GhoGMbWJAa2HsVzt/");
+        self._set_items(&account_id, APPLE_ITEM_ID, 10);
+        self._reward_money(&account_id, 500)
+    }
+
+    /// Overwrite the status of the pet.
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn set_status(
+        &mut self,
+        token_id: Id,
+        hungry: u32,
+        health: u32,
+        happy: u32,
+    ) -> Result<()> {
+        let status = Status {
+            hungry,
+            health,
+            happy,
+        };
+        self.ensure_stat_bounds(&status)?;
+        self._set_status(&token_id, status, StatusCause::GameMaster)
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn set_full_status(&mut self, token_id: Id) -> Result<()> {
+        self.ensure_stat_bounds(&FULL_STATUS)?;
+        self._set_status(&token_id, FULL_STATUS, StatusCause::GameMaster)
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn set_death_status(&mut self, token_id: Id) -> Result<()> {
+        self.ensure_stat_bounds(&DEATH_STATUS)?;
+        self._set_status(&token_id, DEATH_STATUS, StatusCause::GameMaster)
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn set_lucky_status(&mut self, token_id: Id) -> Result<()> {
+        self._change_some_status(&token_id, 50)
+    }
+
+    /// Used to retrieve the stored status of the pet.
+    default fn get_status(&self, token_id: Id) -> Option<Status> {
+        let data = self.data::<PetGameData>();
+        data.asset_status
+            .get(token_id)
+            .map(|status| data.stat_bounds.clamp_status(&status))
+    }
+
+    /// Used to retrieve the status of the pet once the elapsed time is taken into account.
+    default fn get_current_status(&self, token_id: Id) -> Option<Status> {
+        Some(self.decayed_status(&token_id).0)
+    }
+
+    /// Persist the decayed status of the pet.
+    #[modifiers(rate_limited("checkpoint"))]
+    default fn checkpoint(&mut self, token_id: Id) -> Result<()> {
+        self._checkpoint(&token_id)?;
+        Ok(())
+    }
+
+    /// Used to retrieve the time up to which decay is included in the stored status.
+    default fn get_last_checkpoint(&self, token_id: Id) -> u64 {
+        self.data::<PetGameData>()
+            .last_checkpoint
+            .get(&token_id)
+            .unwrap_or_else(|| self.get_last_eaten(token_id))
+    }
+
+    /// Set how the status of the pets decays over time.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_decay_config(&mut self, config: DecayConfig) -> Result<()> {
+        if config.time_unit == 0 {
+            return Err(RmrkError::BadConfig.into())
+        }
+        self.data::<PetGameData>().decay_config = config;
+        Ok(())
+    }
+
+    /// Used to retrieve how the status of the pets decays over time.
+    default fn get_decay_config(&self) -> DecayConfig {
+        self.data::<PetGameData>().decay_config.clone()
+    }
+
+    /// Set the range the stats of the pets are kept in.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_stat_bounds(&mut self, bounds: StatBounds) -> Result<()> {
+        if bounds.max == 0 {
+            return Err(RmrkError::BadConfig.into())
+        }
+        self.data::<PetGameData>().stat_bounds = bounds;
+        Ok(())
+    }
+
+    /// Used to retrieve the range the stats of the pets are kept in.
+    default fn get_stat_bounds(&self) -> StatBounds {
+        self.data::<PetGameData>().stat_bounds
+    }
+
+    /// Set the weighted outcomes of feeding.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_feed_outcomes(&mut self, outcomes: Vec<FeedOutcome>) -> Result<()> {
+        let total_weight = outcomes
+            .iter()
+            .try_fold(0u32, |total, outcome| total.checked_add(outcome.weight));
+        if !matches!(total_weight, Some(total) if total > 0) {
+            return Err(RmrkError::BadConfig.into())
+        }
+        self.data::<PetGameData>().feed_outcomes = outcomes;
+        Ok(())
+    }
+
+    /// Used to retrieve the weighted outcomes of feeding.
+    default fn get_feed_outcomes(&self) -> Vec<FeedOutcome> {
+        let outcomes = &self.data::<PetGameData>().feed_outcomes;
+        if outcomes.is_empty() {
+            return default_feed_outcomes()
+        }
+        outcomes.clone()
+    }
+
+    /// Used to retrieve the chance of each outcome of feeding in basis points.
+    default fn get_feed_odds(&self) -> Vec<u32> {
+        let outcomes = self.get_feed_outcomes();
+        let total_weight: u64 = outcomes.iter().map(|outcome| outcome.weight as u64).sum();
+        outcomes
+            .iter()
+            .map(|outcome| (outcome.weight as u64 * 10_000 / total_weight) as u32)
+            .collect()
+    }
+
+    /// Set where the outcomes of feeding are drawn from.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_randomness_kind(&mut self, kind: RandomnessKind) -> Result<()> {
+        self.data::<PetGameData>().randomness_kind = kind;
+        Ok(())
+    }
+
+    /// Used to retrieve where the outcomes of feeding are drawn from.
+    default fn get_randomness_kind(&self) -> RandomnessKind {
+        self.data::<PetGameData>().randomness_kind
+    }
+
+    /// Commit to a secret used for the next feeding of the pet.
+    #[modifiers(rate_limited("randomness"))]
+    default fn commit_randomness(&mut self, token_id: Id, commitment: Hash) -> Result<()> {
+        self.ensure_nft_owner(&token_id)?;
+        self._commit_randomness(&token_id, commitment)
+    }
+
+    /// Reveal the secret committed for the pet and use `item_id` on it with the seed.
+    #[modifiers(rate_limited("randomness"))]
+    default fn reveal_randomness(
+        &mut self,
+        token_id: Id,
+        secret: Vec<u8>,
+        item_id: ItemId,
+    ) -> Result<()> {
+        self.ensure_nft_owner(&token_id)?;
+        self._reveal_randomness(&token_id, &secret)?;
+        self.use_item(token_id.clone(), item_id)?;
+        // Items that don't roll leave the seed, which mustn't outlive the message
+        self.data::<PetGameData>().revealed_seeds.remove(&token_id);
+        Ok(())
+    }
+
+    /// Used to retrieve the stage of life of the pet at the current time.
+    default fn get_lifecycle_stage(&self, token_id: Id) -> LifecycleStage {
+        self.lifecycle_stage(&token_id)
+    }
+
+    /// Used to retrieve the time the pet was first looked after. 0 if it never was.
+    default fn get_born_at(&self, token_id: Id) -> u64 {
+        self.data::<PetGameData>()
+            .born_at
+            .get(token_id)
+            .unwrap_or_default()
+    }
+
+    /// Set when the pets move to their next stage of life.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_lifecycle_config(&mut self, config: LifecycleConfig) -> Result<()> {
+        if config.hatch_age > config.adult_age || config.adult_age > config.elder_age {
+            return Err(RmrkError::BadConfig.into())
+        }
+        self.data::<PetGameData>().lifecycle_config = config;
+        Ok(())
+    }
+
+    /// Used to retrieve when the pets move to their next stage of life.
+    default fn get_lifecycle_config(&self) -> LifecycleConfig {
+        self.data::<PetGameData>().lifecycle_config.clone()
+    }
+
+    /// Set the collection asset rendered for pets in `stage`. `None` keeps their assets as they are.
+    #[modifiers(only_role(CONTRIBUTOR))]
+    default fn set_stage_asset(
+        &mut self,
+        stage: LifecycleStage,
+        asset_id: Option<AssetId>,
+    ) -> Result<()> {
+        match asset_id {
+            None => self.data::<PetGameData>().stage_assets.remove(stage),
+            Some(asset_id) => {
+                self.collection_asset_uri(asset_id)
+                    .ok_or(RmrkError::AssetIdNotFound)?;
+                self.data::<PetGameData>()
+                    .stage_assets
+                    .insert(stage, &asset_id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Used to retrieve the collection asset rendered for pets in `stage`.
+    default fn get_stage_asset(&self, stage: LifecycleStage) -> Option<AssetId> {
+        self.data::<PetGameData>().stage_assets.get(stage)
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn add_twenty(&mut self, token_id: Id) -> Result<()> {
+        self._change_some_status(&token_id, 20)
+    }
+
+    /// Improve every stat of the pet by `number`.
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn change_some_status(&mut self, token_id: Id, number: u32) -> Result<()> {
+        self._change_some_status(&token_id, number)
+    }
+
+    #[modifiers(only_role(CONTRIBUTOR))]
+    default fn set_normal_uri(&mut self, normal_uri: String) -> Result<()> {
+        self.data::<PetGameData>().normal_uri = normal_uri;
+        Ok(())
+    }
+
+    default fn get_normal_uri(&self) -> String {
+        self.data::<PetGameData>().normal_uri.clone()
+    }
+
+    #[modifiers(only_role(CONTRIBUTOR))]
+    default fn set_good_uri(&mut self, good_uri: String) -> Result<()> {
+        self.data::<PetGameData>().good_uri = good_uri;
+        Ok(())
+    }
+
+    default fn get_good_uri(&self) -> String {
+        self.data::<PetGameData>().good_uri.clone()
+    }
+
+    #[modifiers(only_role(CONTRIBUTOR))]
+    default fn set_bad_uri(&mut self, bad_uri: String) -> Result<()> {
+        self.data::<PetGameData>().bad_uri = bad_uri;
+        Ok(())
+    }
+
+    default fn get_bad_uri(&self) -> String {
+        self.data::<PetGameData>().bad_uri.clone()
+    }
+
+    /// Used to retrieve the sum of health and happiness minus hunger.
+    default fn get_total_status(&self, token_id: Id) -> u32 {
+        self.decayed_status(&token_id).0.total()
+    }
+
+    /// Used to retrieve the condition of the pet from its total status.
+    default fn get_condition(&self, token_id: Id) -> Condition {
+        self.decayed_status(&token_id).0.condition()
+    }
+
+    /// Used to retrieve the index of the condition tier of the pet from the lowest one.
+    default fn get_condition_tier(&self, token_id: Id) -> u32 {
+        let tiers = &self.data::<PetGameData>().condition_tiers;
+        if tiers.is_empty() {
+            return self.get_condition(token_id) as u32
+        }
+
+        let total_status = self.get_total_status(token_id);
+        let mut thresholds: Vec<u32> = tiers
+            .iter()
+            .map(|tier| tier.threshold)
+            .filter(|threshold| *threshold <= total_status)
+            .collect();
+        thresholds.dedup();
+        thresholds.len().saturating_sub(1) as u32
+    }
+
+    /// Used to retrieve the uri matching the condition of the pet.
+    default fn get_condition_url(&self, token_id: Id) -> String {
+        if let Some(asset_id) = self.get_condition_asset(token_id.clone()) {
+            return self.collection_asset_uri(asset_id).unwrap_or_default()
+        }
+        match self.get_condition(token_id) {
+            Condition::Bad => self.get_bad_uri(),
+            Condition::Normal => self.get_normal_uri(),
+            Condition::Good => self.get_good_uri(),
+        }
+    }
+
+    /// Set the tiers of total status mapped to collection assets, by increasing threshold.
+    #[modifiers(only_role(CONTRIBUTOR))]
+    default fn set_condition_tiers(&mut self, tiers: Vec<ConditionTier>) -> Result<()> {
+        if tiers
+            .windows(2)
+            .any(|pair| pair[0].threshold > pair[1].threshold)
+        {
+            return Err(RmrkError::BadConfig.into())
+        }
+        for tier in tiers.iter() {
+            self.collection_asset_uri(tier.asset_id)
+                .ok_or(RmrkError::AssetIdNotFound)?;
+        }
+        self.data::<PetGameData>().condition_tiers = tiers;
+        Ok(())
+    }
+
+    /// Used to retrieve the tiers of total status mapped to collection assets.
+    default fn get_condition_tiers(&self) -> Vec<ConditionTier> {
+        self.data::<PetGameData>().condition_tiers.clone()
+    }
+
+    /// Used to retrieve the asset rendered for the condition of the pet.
+    default fn get_condition_asset(&self, token_id: Id) -> Option<AssetId> {
+        let tiers = &self.data::<PetGameData>().condition_tiers;
+        let total_status = self.get_total_status(token_id.clone());
+        // Pets below the lowest threshold are shown with the lowest tier
+        let threshold = tiers
+            .iter()
+            .map(|tier| tier.threshold)
+            .filter(|threshold| *threshold <= total_status)
+            .last()
+            .or_else(|| tiers.first().map(|tier| tier.threshold))?;
+        let tier_assets: Vec<AssetId> = tiers
+            .iter()
+            .filter(|tier| tier.threshold == threshold)
+            .map(|tier| tier.asset_id)
+            .collect();
+
+        // Accepted assets are stored by decreasing priority
+        self.pet_accepted_assets(&token_id)
+            .into_iter()
+            .find(|asset_id| tier_assets.contains(asset_id))
+            .or_else(|| tier_assets.first().copied())
+    }
+
+    /// Used to retrieve the mood of the pet from its current status.
+    default fn get_mood(&self, token_id: Id) -> Mood {
+        self.decayed_status(&token_id).0.mood()
+    }
+
+    /// Used to retrieve the status, condition, mood, stage of life, last meal and uri of the pet.
+    default fn get_pet_view(&self, token_id: Id) -> Option<PetView> {
+        self.pet_owner(&token_id)?;
+        let status = self.decayed_status(&token_id).0;
+        Some(PetView {
+            total_status: status.total(),
+            condition: status.condition(),
+            mood: status.mood(),
+            status,
+            stage: self.get_lifecycle_stage(token_id.clone()),
+            last_eaten: self.get_last_eaten(token_id.clone()),
+            uri: self.token_uri(token_id),
+        })
+    }
+
+    /// Feed an apple owned by `account_id` to the pet.
+    /// The caller must own both the pet and the account.
+    #[modifiers(rate_limited("feed"))]
+    default fn eat_an_apple(&mut self, token_id: Id, account_id: AccountId) -> Result<()> {
+        self.ensure_nft_owner(&token_id)?;
+        self.ensure_account_id(&account_id)?;
+        self._use_item(&token_id, &account_id, APPLE_ITEM_ID)
+    }
+
+    /// Register a new item. Return its id.
+    #[modifiers(only_role(ADMIN))]
+    default fn add_item(&mut self, item: Item) -> Result<ItemId> {
+        let item_id = self
+            .data::<PetGameData>()
+            .last_item_id
+            .checked_add(1)
+            .ok_or(RmrkError::TooManyItems)?;
+        self.data::<PetGameData>().last_item_id = item_id;
+        self.data::<PetGameData>().items.insert(item_id, &item);
+        Ok(item_id)
+    }
+
+    /// Replace a registered item.
+    #[modifiers(only_role(ADMIN))]
+    default fn update_item(&mut self, item_id: ItemId, item: Item) -> Result<()> {
+        if item_id > self.get_last_item_id() {
+            return Err(RmrkError::ItemNotFound.into())
+        }
+        self.data::<PetGameData>().items.insert(item_id, &item);
+        Ok(())
+    }
+
+    /// Used to retrieve a registered item.
+    default fn get_item(&self, item_id: ItemId) -> Option<Item> {
+        match self.data::<PetGameData>().items.get(item_id) {
+            None if item_id == APPLE_ITEM_ID => Some(default_apple()),
+            item => item,
+        }
+    }
+
+    /// Used to retrieve the id of the last registered item.
+    default fn get_last_item_id(&self) -> ItemId {
+        self.data::<PetGameData>().last_item_id
+    }
+
+    /// Used to retrieve the number of `item_id` owned by the account.
+    default fn get_item_balance(&self, account_id: AccountId, item_id: ItemId) -> u32 {
+        self.season_items(&account_id, item_id, self.data::<PetGameData>().season)
+    }
+
+    /// Used to retrieve the last time `item_id` was used on the pet.
+    default fn get_item_last_used(&self, token_id: Id, item_id: ItemId) -> u64 {
+        self.get_last_used(Cooldown::Item(item_id), CooldownSubject::Token(token_id))
+    }
+
+    /// Set the duration of a cooldown in milliseconds.
+    /// Item cooldowns are part of the item and set with `update_item`
+    #[modifiers(only_role(ADMIN))]
+    default fn set_cooldown(&mut self, cooldown: Cooldown, duration: u64) -> Result<()> {
+        if let Cooldown::Item(_) = cooldown {
+            return Err(RmrkError::BadConfig.into())
+        }
+        self.data::<PetGameData>()
+            .cooldown_durations
+            .insert(cooldown, &duration);
+        Ok(())
+    }
+
+    /// Used to retrieve the duration of a cooldown in milliseconds.
+    default fn get_cooldown(&self, cooldown: Cooldown) -> u64 {
+        match cooldown {
+            Cooldown::Item(item_id) => {
+                self.get_item(item_id)
+                    .map(|item| item.cooldown)
+                    .unwrap_or_default()
+            }
+            _ => {
+                self.data::<PetGameData>()
+                    .cooldown_durations
+                    .get(cooldown)
+                    .unwrap_or_else(|| default_cooldown(cooldown))
+            }
+        }
+    }
+
+    /// Used to retrieve the last time the cooldown started for the token or account.
+    default fn get_last_used(&self, cooldown: Cooldown, subject: CooldownSubject) -> u64 {
+        self.data::<PetGameData>()
+            .last_used
+            .get((cooldown, subject))
+            .unwrap_or_default()
+    }
+
+    /// Used to retrieve the milliseconds left before the cooldown is over for the token or account.
+    default fn get_cooldown_remaining(&self, cooldown: Cooldown, subject: CooldownSubject) -> u64 {
+        self.cooldown_remaining(&cooldown, &subject)
+    }
+
+    /// Buy `qty` of `item_id` with the in-game currency of the caller.
+    #[modifiers(rate_limited("buy"))]
+    default fn buy_item(&mut self, item_id: ItemId, qty: u32) -> Result<()> {
+        let caller = Self::env().caller();
+        let item = self.get_item(item_id).ok_or(RmrkError::ItemNotFound)?;
+        let price = item
+            .price
+            .checked_mul(qty as u64)
+            .ok_or(RmrkError::NotEnoughMoney)?;
+        if self
+            .get_item_balance(caller, item_id)
+            .checked_add(qty)
+            .is_none()
+        {
+            return Err(RmrkError::TooManyItems.into())
+        }
+
+        self._charge_money(&caller, price)?;
+        self._add_items(&caller, item_id, qty)?;
+        self._emit_item_bought_event(&caller, item_id, qty, price);
+        Ok(())
+    }
+
+    /// Use one `item_id` owned by the caller on the pet.
+    #[modifiers(rate_limited("feed"))]
+    default fn use_item(&mut self, token_id: Id, item_id: ItemId) -> Result<()> {
+        self.ensure_nft_owner(&token_id)?;
+        self._use_item(&token_id, &Self::env().caller(), item_id)
+    }
+
+    /// Used to retrieve the uri of the pet for its current condition.
+    default fn token_uri(&self, token_id: Id) -> String {
+        let mut uri = self.get_condition_url(token_id.clone());
+        if self.data::<PetGameData>().condition_tiers.is_empty() {
+            uri.extend_from_slice(&id_to_bytes(&token_id));
+        }
+        uri
+    }
+
+    default fn get_your_apple(&self, account_id: AccountId) -> u16 {
+        let apple_number = self.get_item_balance(account_id, APPLE_ITEM_ID);
+        u16::try_from(apple_number).unwrap_or(u16::MAX)
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn set_your_apple(&mut self, account_id: AccountId, after_apple: u16) -> Result<()> {
+        self._set_items(&account_id, APPLE_ITEM_ID, after_apple as u32);
+        Ok(())
+    }
+
+    /// Used to retrieve the in-game currency owned by the account.
+    default fn get_your_money(&self, account_id: AccountId) -> u64 {
+        match self.get_currency() {
+            None => self.season_money(&account_id, self.data::<PetGameData>().season),
+            Some(currency) => {
+                u64::try_from(PSP22Ref::balance_of(&currency, account_id)).unwrap_or(u64::MAX)
+            }
+        }
+    }
+
+    /// Set the PSP22 contract used as the in-game currency.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_currency(&mut self, currency: Option<AccountId>) -> Result<()> {
+        self.data::<PetGameData>().currency = currency;
+        Ok(())
+    }
+
+    /// Used to retrieve the PSP22 contract used as the in-game currency.
+    default fn get_currency(&self) -> Option<AccountId> {
+        self.data::<PetGameData>().currency
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn set_your_money(&mut self, account_id: AccountId, after_money: u64) -> Result<()> {
+        self._set_your_money(&account_id, after_money);
+        Ok(())
+    }
+
+    /// Buy one apple with the in-game currency.
+    default fn buy_an_apple(&mut self, account_id: AccountId) -> Result<()> {
+        self.ensure_account_id(&account_id)?;
+        self.buy_item(APPLE_ITEM_ID, 1)
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn minus_your_apple(&mut self, account_id: AccountId) -> Result<()> {
+        self._remove_items(&account_id, APPLE_ITEM_ID, 1)
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn minus_your_money(&mut self, account_id: AccountId, change_money: u64) -> Result<()> {
+        self._minus_your_money(&account_id, change_money)
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn plus_your_money(&mut self, account_id: AccountId, change_money: u64) -> Result<()> {
+        self._plus_your_money(&account_id, change_money)
+    }
+
+    /// Credit the daily bonus of the current streak day to the caller.
+    #[modifiers(rate_limited("daily_bonus"))]
+    default fn daily_bonus(&mut self, account_id: AccountId) -> Result<()> {
+        self.ensure_account_id(&account_id)?;
+        let subject = CooldownSubject::Account(account_id);
+        self.ensure_cooldown(&Cooldown::DailyBonus, &subject)?;
+
+        let streak = self._next_bonus_streak(&account_id);
+        let reward = self._bonus_reward(streak);
+        self._start_cooldown(&Cooldown::DailyBonus, &subject);
+        self.data::<PetGameData>()
+            .bonus_streaks
+            .insert(account_id, &streak);
+        if let Some((item_id, amount)) = reward.item {
+            self._add_items(&account_id, item_id, amount)?;
+        }
+        if reward.money > 0 {
+            self._reward_money(&account_id, reward.money)?;
+        }
+        self._emit_bonus_claimed_event(&account_id, streak, &reward);
+        Ok(())
+    }
+
+    /// Set the rewards of the daily bonus streak and its claim window.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_bonus_config(&mut self, config: BonusConfig) -> Result<()> {
+        if config.rewards.is_empty() {
+            return Err(RmrkError::BadConfig.into())
+        }
+        self.data::<PetGameData>().bonus_config = config;
+        Ok(())
+    }
+
+    /// Used to retrieve the rewards of the daily bonus streak and its claim window.
+    default fn get_bonus_config(&self) -> BonusConfig {
+        self.data::<PetGameData>().bonus_config.clone()
+    }
+
+    /// Used to retrieve the current daily bonus streak of the account.
+    default fn get_bonus_streak(&self, account_id: AccountId) -> u32 {
+        if self._next_bonus_streak(&account_id) == 1 {
+            return 0
+        }
+        self.data::<PetGameData>()
+            .bonus_streaks
+            .get(account_id)
+            .unwrap_or_default()
+    }
+
+    /// Used to retrieve the reward of the next daily bonus claim of the account.
+    default fn get_next_bonus(&self, account_id: AccountId) -> BonusReward {
+        self._bonus_reward(self._next_bonus_streak(&account_id))
+    }
+
+    default fn get_last_eaten(&self, token_id: Id) -> u64 {
+        self.data::<PetGameData>()
+            .last_eaten
+            .get(token_id)
+            .unwrap_or_default()
+    }
+
+    /// Used to retrieve the number of times the account fed its pets.
+    default fn get_feed_count(&self, account_id: AccountId) -> u32 {
+        self.data::<PetGameData>()
+            .feed_counts
+            .get(account_id)
+            .unwrap_or_default()
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn set_last_eaten(&mut self, token_id: Id, current_time: u64) -> Result<()> {
+        // The grace period depends on the last meal, so persist the decay up to now first
+        self._checkpoint(&token_id)?;
+        self.data::<PetGameData>()
+            .last_eaten
+            .insert(token_id, &current_time);
+        Ok(())
+    }
+
+    default fn get_last_bonus(&self, account_id: AccountId) -> u64 {
+        self.get_last_used(Cooldown::DailyBonus, CooldownSubject::Account(account_id))
+    }
+
+    #[modifiers(only_role(GAME_MASTER))]
+    default fn set_last_bonus(&mut self, account_id: AccountId, current_time: u64) -> Result<()> {
+        self.data::<PetGameData>().last_used.insert(
+            (Cooldown::DailyBonus, CooldownSubject::Account(account_id)),
+            &current_time,
+        );
+        Ok(())
+    }
+
+    /// Used to retrieve the currency staked by the account over all its stakes.
+    default fn get_staked_money(&self, account_id: AccountId) -> u64 {
+        self.season_staked_money(&account_id, self.data::<PetGameData>().season)
+    }
+
+    /// Used to retrieve the ranked entries of a leaderboard from `offset`, at most `limit` of them.
+    default fn get_leaderboard(
+        &self,
+        kind: LeaderboardKind,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(u32, LeaderboardEntry)> {
+        let entries = self
+            .data::<PetGameData>()
+            .leaderboards
+            .get(kind)
+            .unwrap_or_default();
+        rank_entries(entries, offset, limit)
+    }
+
+    /// Used to retrieve the ranked entries a leaderboard had when `season` ended.
+    default fn get_leaderboard_snapshot(
+        &self,
+        season: u32,
+        kind: LeaderboardKind,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(u32, LeaderboardEntry)> {
+        let entries = self
+            .data::<PetGameData>()
+            .leaderboard_snapshots
+            .get((season, kind))
+            .unwrap_or_default();
+        rank_entries(entries, offset, limit)
+    }
+
+    /// Used to retrieve the season of the current leaderboards.
+    default fn get_leaderboard_season(&self) -> u32 {
+        self.data::<PetGameData>().leaderboard_season
+    }
+
+    /// Set the number of entries kept on each leaderboard.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_leaderboard_size(&mut self, size: u32) -> Result<()> {
+        if size == 0 || size > MAX_LEADERBOARD_SIZE {
+            return Err(RmrkError::BadConfig.into())
+        }
+        self.data::<PetGameData>().leaderboard_size = size;
+        for kind in LEADERBOARD_KINDS {
+            let mut entries = self
+                .data::<PetGameData>()
+                .leaderboards
+                .get(kind)
+                .unwrap_or_default();
+            if entries.len() > size as usize {
+                entries.truncate(size as usize);
+                self.data::<PetGameData>()
+                    .leaderboards
+                    .insert(kind, &entries);
+            }
+        }
+        Ok(())
+    }
+
+    /// Used to retrieve the number of entries kept on each leaderboard.
+    default fn get_leaderboard_size(&self) -> u32 {
+        match self.data::<PetGameData>().leaderboard_size {
+            0 => DEFAULT_LEADERBOARD_SIZE,
+            size => size,
+        }
+    }
+
+    /// Keep the current leaderboards as the snapshot of the season and start a new one.
+    #[modifiers(only_role(ADMIN))]
+    default fn reset_leaderboards(&mut self) -> Result<()> {
+        let season = self.get_leaderboard_season();
+        let next_season = season.checked_add(1).ok_or(RmrkError::BadConfig)?;
+        let data = self.data::<PetGameData>();
+        for kind in LEADERBOARD_KINDS {
+            let entries = data.leaderboards.get(kind).unwrap_or_default();
+            data.leaderboard_snapshots.insert((season, kind), &entries);
+            data.leaderboards.remove(kind);
+        }
+        data.leaderboard_season = next_season;
+        Ok(())
+    }
+
+    /// Set the number of entries kept in the status history of each pet.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_status_history_capacity(&mut self, capacity: u32) -> Result<()> {
+        if capacity == 0 || capacity > MAX_STATUS_HISTORY_CAPACITY {
+            return Err(RmrkError::BadConfig.into())
+        }
+        let data = self.data::<PetGameData>();
+        let generation = data
+            .status_history_generation
+            .checked_add(1)
+            .ok_or(RmrkError::BadConfig)?;
+        // Capacities at or above the new one no longer bound any history
+        let mut len = data.status_history_lows_len;
+        while let Some(top) = len.checked_sub(1) {
+            match data.status_history_lows.get(top) {
+                Some((_, low)) if low >= capacity => {
+                    data.status_history_lows.remove(top);
+                    len = top;
+                }
+                _ => break,
+            }
+        }
+        data.status_history_lows
+            .insert(len, &(generation, capacity));
+        data.status_history_lows_len = len + 1;
+        data.status_history_generation = generation;
+        data.status_history_capacity = capacity;
+        Ok(())
+    }
+
+    /// Used to retrieve the number of entries kept in the status history of each pet.
+    default fn get_status_history_capacity(&self) -> u32 {
+        match self.data::<PetGameData>().status_history_capacity {
+            0 => DEFAULT_STATUS_HISTORY_CAPACITY,
+            capacity => capacity,
+        }
+    }
+
+    /// Used to retrieve the number of entries ever added to the status history of the pet.
+    default fn get_status_history_len(&self, token_id: Id) -> u32 {
+        self.data::<PetGameData>()
+            .status_history_len
+            .get(token_id)
+            .unwrap_or_default()
+    }
+
+    /// Used to retrieve the entries of the status history of the pet from the sequence number
+    /// `from`, at most `limit` of them.
+    default fn get_status_history(
+        &self,
+        token_id: Id,
+        from: u32,
+        limit: u32,
+    ) -> Vec<(u32, StatusRecord)> {
+        let len = self.get_status_history_len(token_id.clone());
+        let oldest = self.status_history_start(&token_id);
+        let data = self.data::<PetGameData>();
+        (from.max(oldest)..len)
+            .filter_map(|index| {
+                data.status_history
+                    .get((token_id.clone(), index))
+                    .map(|record| (index, record))
+            })
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Check if the caller owns `token_id`.
+    default fn is_nft_owner(&self, token_id: Id) -> bool {
+        self.pet_owner(&token_id)
+            .map_or(false, |owner| owner == Self::env().caller())
+    }
+
+    /// Check if the caller is `account_id`.
+    default fn is_account_id(&self, account_id: AccountId) -> bool {
+        Self::env().caller() == account_id
+    }
+}
+
+/// Event trait for PetGame
+impl<T> PetGameEvents for T
+where
+    T: Storage<PetGameData>,
+{
+    /// Used to notify listeners that the stored status of a pet has changed.
+    default fn _emit_status_changed_event(&self, _token_id: &Id, _status: &Status) {}
+
+    /// Used to notify listeners that an item was used on a pet.
+    default fn _emit_item_used_event(
+        &self,
+        _token_id: &Id,
+        _account_id: &AccountId,
+        _item_id: ItemId,
+    ) {
+    }
+
+    /// Used to notify listeners that a pet moved to another stage of life.
+    default fn _emit_lifecycle_changed_event(&self, _token_id: &Id, _stage: LifecycleStage) {}
+
+    /// Used to notify listeners that two pets played together.
+    default fn _emit_pets_played_event(&self, _token_id: &Id, _other_token_id: &Id) {}
+
+    /// Used to notify listeners that two pets had a child.
+    default fn _emit_pet_bred_event(&self, _parent_id: &Id, _other_parent_id: &Id, _child_id: &Id) {
+    }
+
+    /// Used to notify listeners that the daily bonus was claimed.
+    default fn _emit_bonus_claimed_event(
+        &self,
+        _account_id: &AccountId,
+        _streak: u32,
+        _reward: &BonusReward,
+    ) {
+    }
+
+    /// Used to notify listeners which outcome a feeding rolled.
+    default fn _emit_feed_outcome_event(
+        &self,
+        _token_id: &Id,
+        _index: u32,
+        _effect: &StatusEffect,
+    ) {
+    }
+    /// Used to notify listeners that items were bought.
+    default fn _emit_item_bought_event(
+        &self,
+        _account_id: &AccountId,
+        _item_id: ItemId,
+        _qty: u32,
+        _price: u64,
+    ) {
+    }
+
+    /// Used to notify listeners that a shop listing was bought.
+    default fn _emit_listing_bought_event(
+        &self,
+        _account_id: &AccountId,
+        _listing_id: ListingId,
+        _qty: u32,
+        _price: Balance,
+    ) {
+    }
+
+    /// Used to notify listeners that currency was staked.
+    default fn _emit_staked_event(
+        &self,
+        _account_id: &AccountId,
+        _lock_id: Option<u32>,
+        _amount: u64,
+    ) {
+    }
+
+    /// Used to notify listeners that currency was withdrawn from a stake.
+    default fn _emit_unstaked_event(
+        &self,
+        _account_id: &AccountId,
+        _lock_id: Option<u32>,
+        _amount: u64,
+        _rewards: u64,
+    ) {
+    }
+
+    /// Used to notify listeners that staking rewards were claimed.
+    default fn _emit_staking_rewards_claimed_event(
+        &self,
+        _account_id: &AccountId,
+        _lock_id: Option<u32>,
+        _rewards: u64,
+    ) {
+    }
+
+    /// Used to notify listeners that a quest was claimed.
+    default fn _emit_quest_claimed_event(
+        &self,
+        _account_id: &AccountId,
+        _quest_id: QuestId,
+        _token_id: &Id,
+    ) {
+    }
+}
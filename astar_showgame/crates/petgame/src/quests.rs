@@ -0,0 +1,270 @@
+//! RMRK PetGame quests implementation
+use crate::{
+    collection::PetCollection,
+    internal::Internal,
+    rate_limit::rate_limited,
+    traits::{
+        PetGame,
+        PetGameEvents,
+        Quests,
+        Staking,
+    },
+    PetGameData,
+    QuestData,
+    RateLimitData,
+    StakingData,
+    DAY,
+    HEALTH_DAYS_KEPT,
+};
+
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::ADMIN,
+    types::*,
+};
+
+use ink::prelude::{
+    string::ToString,
+    vec::Vec,
+};
+
+use openbrush::{
+    contracts::{
+        access_control::*,
+        psp34::Id,
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+/// Trait definitions for Quests helper functions
+pub trait QuestsInternal {
+    /// Check that the reward items exist and that the condition can be reached
+    fn ensure_valid_quest(&self, quest: &Quest) -> Result<()>;
+
+    /// Count the days in a row up to today the health of the pet stayed at or above `health`
+    fn healthy_days(&self, token_id: &Id, health: u32) -> u32;
+
+    /// Sum the stakes of the account locked for at least `lock` more milliseconds
+    fn locked_stake(&self, account_id: &AccountId, lock: u64) -> u64;
+
+    /// Compute the progress of the account on the condition and the target to reach
+    fn quest_progress(
+        &self,
+        condition: &QuestCondition,
+        account_id: &AccountId,
+        token_id: &Id,
+    ) -> (u64, u64);
+
+    /// Return the time the quest was claimed. Quests reached by pets are claimed once per pet,
+    /// whoever owns it, the others once per account
+    fn quest_claimed_at(
+        &self,
+        quest_id: QuestId,
+        condition: &QuestCondition,
+        account_id: &AccountId,
+        token_id: &Id,
+    ) -> Option<u64>;
+}
+
+/// Implement internal helper trait for Quests
+impl<T> QuestsInternal for T
+where
+    T: Storage<QuestData> + Storage<PetGameData> + PetGame + Staking + PetCollection,
+{
+    default fn ensure_valid_quest(&self, quest: &Quest) -> Result<()> {
+        for (item_id, _) in quest.items.iter() {
+            self.get_item(*item_id).ok_or(RmrkError::ItemNotFound)?;
+        }
+        match quest.condition {
+            QuestCondition::KeepHealth { days, .. } if days == 0 || days > HEALTH_DAYS_KEPT => {
+                Err(RmrkError::BadConfig.into())
+            }
+            // Stakes withdrawn right after the claim would reach the quest for free
+            QuestCondition::Stake { lock: 0, .. } => Err(RmrkError::BadConfig.into()),
+            _ => Ok(()),
+        }
+    }
+
+    default fn healthy_days(&self, token_id: &Id, health: u32) -> u32 {
+        // Health may have decayed below the threshold since the last update
+        if self.decayed_status(token_id).0.health < health {
+            return 0
+        }
+        let today = Self::env().block_timestamp() / DAY;
+        self.data::<PetGameData>()
+            .health_lows
+            .get(token_id)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .take_while(|(index, (day, low))| {
+                today.checked_sub(*index as u64) == Some(*day) && *low >= health
+            })
+            .count() as u32
+    }
+
+    default fn locked_stake(&self, account_id: &AccountId, lock: u64) -> u64 {
+        let locked_until = Self::env().block_timestamp().saturating_add(lock);
+        let lock_options = self.get_staking_config().lock_options.len() as u32;
+        (0..lock_options)
+            .filter_map(|lock_id| self.get_stake(*account_id, Some(lock_id)))
+            .filter(|position| position.unlock_at >= locked_until)
+            .fold(0, |total, position| total.saturating_add(position.amount))
+    }
+
+    default fn quest_progress(
+        &self,
+        condition: &QuestCondition,
+        account_id: &AccountId,
+        token_id: &Id,
+    ) -> (u64, u64) {
+        match *condition {
+            QuestCondition::Feed(count) => (self.get_feed_count(*account_id) as u64, count as u64),
+            QuestCondition::KeepHealth { health, days } => {
+                (self.healthy_days(token_id, health) as u64, days as u64)
+            }
+            QuestCondition::Stake { amount, lock } => (self.locked_stake(account_id, lock), amount),
+            QuestCondition::BonusStreak(streak) => {
+                (self.get_bonus_streak(*account_id) as u64, streak as u64)
+            }
+        }
+    }
+
+    default fn quest_claimed_at(
+        &self,
+        quest_id: QuestId,
+        condition: &QuestCondition,
+        account_id: &AccountId,
+        token_id: &Id,
+    ) -> Option<u64> {
+        let data = self.data::<QuestData>();
+        if condition.is_per_pet() {
+            data.pet_claimed_at.get((quest_id, token_id.clone()))
+        } else {
+            data.claimed_at.get((quest_id, *account_id))
+        }
+    }
+}
+
+impl<T> Quests for T
+where
+    T: Storage<QuestData>
+        + Storage<PetGameData>
+        + Storage<StakingData>
+        + Storage<RateLimitData>
+        + Storage<access_control::Data>
+        + PetCollection,
+{
+    /// Register a new quest. Return its id.
+    #[modifiers(only_role(ADMIN))]
+    default fn add_quest(&mut self, quest: Quest) -> Result<QuestId> {
+        self.ensure_valid_quest(&quest)?;
+        let quest_id = self
+            .data::<QuestData>()
+            .last_quest_id
+            .checked_add(1)
+            .ok_or(RmrkError::BadConfig)?;
+        let data = self.data::<QuestData>();
+        data.last_quest_id = quest_id;
+        data.quests.insert(quest_id, &quest);
+        Ok(quest_id)
+    }
+
+    /// Replace a quest.
+    #[modifiers(only_role(ADMIN))]
+    default fn update_quest(&mut self, quest_id: QuestId, quest: Quest) -> Result<()> {
+        self.get_quest(quest_id).ok_or(RmrkError::QuestNotFound)?;
+        self.ensure_valid_quest(&quest)?;
+        self.data::<QuestData>().quests.insert(quest_id, &quest);
+        Ok(())
+    }
+
+    /// Remove a quest.
+    #[modifiers(only_role(ADMIN))]
+    default fn remove_quest(&mut self, quest_id: QuestId) -> Result<()> {
+        self.get_quest(quest_id).ok_or(RmrkError::QuestNotFound)?;
+        self.data::<QuestData>().quests.remove(quest_id);
+        Ok(())
+    }
+
+    /// Used to retrieve a quest.
+    default fn get_quest(&self, quest_id: QuestId) -> Option<Quest> {
+        self.data::<QuestData>().quests.get(quest_id)
+    }
+
+    /// Used to retrieve the registered quests along with their ids, among at most `limit` ids
+    /// from `from`.
+    default fn get_quests(&self, from: QuestId, limit: u32) -> Vec<(QuestId, Quest)> {
+        (from.max(1)..=self.data::<QuestData>().last_quest_id)
+            .take(limit as usize)
+            .filter_map(|quest_id| self.get_quest(quest_id).map(|quest| (quest_id, quest)))
+            .collect()
+    }
+
+    /// Used to retrieve the progress of the account on the quest and the target to reach.
+    default fn get_quest_progress(
+        &self,
+        quest_id: QuestId,
+        account_id: AccountId,
+        token_id: Id,
+    ) -> Result<(u64, u64)> {
+        let quest = self.get_quest(quest_id).ok_or(RmrkError::QuestNotFound)?;
+        Ok(self.quest_progress(&quest.condition, &account_id, &token_id))
+    }
+
+    /// Claim the rewards of a completed quest for the caller.
+    #[modifiers(rate_limited("quest"))]
+    default fn claim_quest(&mut self, quest_id: QuestId, token_id: Id) -> Result<()> {
+        let caller = self.ensure_nft_owner(&token_id)?;
+        let quest = self.get_quest(quest_id).ok_or(RmrkError::QuestNotFound)?;
+        if self
+            .quest_claimed_at(quest_id, &quest.condition, &caller, &token_id)
+            .is_some()
+        {
+            return Err(RmrkError::QuestAlreadyClaimed.into())
+        }
+        let (progress, target) = self.quest_progress(&quest.condition, &caller, &token_id);
+        if progress < target {
+            return Err(RmrkError::QuestNotCompleted.into())
+        }
+
+        let current_time = Self::env().block_timestamp();
+        let data = self.data::<QuestData>();
+        if quest.condition.is_per_pet() {
+            data.pet_claimed_at
+                .insert((quest_id, token_id.clone()), &current_time);
+        } else {
+            data.claimed_at.insert((quest_id, caller), &current_time);
+        }
+        for (item_id, amount) in quest.items {
+            self._add_items(&caller, item_id, amount)?;
+        }
+        if quest.money > 0 {
+            self._reward_money(&caller, quest.money)?;
+        }
+        if let Some(achievement) = quest.achievement {
+            // Attributes can't be moved to another token, which keeps the badge on the pet
+            self._set_pet_attribute(&token_id, achievement, quest_id.to_string().into_bytes())?;
+        }
+        self._emit_quest_claimed_event(&caller, quest_id, &token_id);
+        Ok(())
+    }
+
+    /// Used to retrieve the time the quest was claimed by the account, or with the pet.
+    default fn get_quest_claimed_at(
+        &self,
+        quest_id: QuestId,
+        account_id: AccountId,
+        token_id: Id,
+    ) -> Option<u64> {
+        let quest = self.get_quest(quest_id)?;
+        self.quest_claimed_at(quest_id, &quest.condition, &account_id, &token_id)
+    }
+}
@@ -0,0 +1,186 @@
+//! Sources of randomness for the PetGame module
+use crate::PetGameData;
+
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    types::*,
+};
+
+use ink::{
+    env::{
+        chain_extension::{
+            ChainExtensionMethod,
+            FromStatusCode,
+        },
+        hash,
+    },
+    prelude::vec::Vec,
+};
+
+use openbrush::{
+    contracts::psp34::Id,
+    traits::{
+        Hash,
+        Storage,
+    },
+};
+
+use scale::Encode;
+
+/// Function id of the runtime chain extension returning 32 verifiable random bytes
+pub const VRF_FUNCTION_ID: u32 = 0x0001_0001;
+
+/// Status code returned by the VRF chain extension when it can't serve the request
+pub struct VrfUnavailable;
+
+impl FromStatusCode for VrfUnavailable {
+    fn from_status_code(status_code: u32) -> core::result::Result<(), Self> {
+        match status_code {
+            0 => Ok(()),
+            _ => Err(VrfUnavailable),
+        }
+    }
+}
+
+/// Turn random bytes into a number below `bound`. Return 0 if `bound` is 0
+pub fn roll(output: &[u8; 32], bound: u32) -> u32 {
+    let roll = u32::from_be_bytes([output[0], output[1], output[2], output[3]]);
+    roll.checked_rem(bound).unwrap_or_default()
+}
+
+/// Hash `input` with Keccak256
+pub fn keccak(input: &[u8]) -> [u8; 32] {
+    let mut output = <hash::Keccak256 as hash::HashOutput>::Type::default();
+    ink::env::hash_bytes::<hash::Keccak256>(input, &mut output);
+    output
+}
+
+/// Trait definitions for the sources of randomness used by the game
+pub trait RandomnessSource {
+    /// Draw a number below `bound` for the pet from the configured source
+    fn _random(&mut self, token_id: &Id, bound: u32) -> Result<u32>;
+
+    /// Hash the block timestamp with an incrementing salt
+    fn _hash_randomness(&mut self, token_id: &Id) -> Result<[u8; 32]>;
+
+    /// Store the commitment of the pet owner to a secret
+    fn _commit_randomness(&mut self, token_id: &Id, commitment: Hash) -> Result<()>;
+
+    /// Check the secret against the commitment and store the seed derived from it
+    /// for the rest of the message
+    fn _reveal_randomness(&mut self, token_id: &Id, secret: &[u8]) -> Result<()>;
+
+    /// Consume the seed revealed by the pet owner in this message, mixed with entropy drawn now
+    fn _commit_reveal_randomness(&mut self, token_id: &Id, vrf: bool) -> Result<[u8; 32]>;
+
+    /// Request random bytes from the runtime VRF through the chain extension
+    fn _vrf_randomness(&mut self, token_id: &Id) -> Result<[u8; 32]>;
+}
+
+/// Implement the sources of randomness for PetGame
+impl<T> RandomnessSource for T
+where
+    T: Storage<PetGameData>,
+{
+    /// Draw a number below `bound` for the pet from the configured source.
+    /// Return 0 if `bound` is 0
+    default fn _random(&mut self, token_id: &Id, bound: u32) -> Result<u32> {
+        let output = match self.data::<PetGameData>().randomness_kind {
+            RandomnessKind::Hash => self._hash_randomness(token_id)?,
+            RandomnessKind::CommitReveal { vrf } => {
+                self._commit_reveal_randomness(token_id, vrf)?
+            }
+            RandomnessKind::ChainExtension => self._vrf_randomness(token_id)?,
+        };
+        Ok(roll(&output, bound))
+    }
+
+    /// Hash the block timestamp with an incrementing salt.
+    /// Block authors and callers picking their block can predict the result
+    default fn _hash_randomness(&mut self, _token_id: &Id) -> Result<[u8; 32]> {
+        let seed = Self::env().block_timestamp();
+        let mut input: Vec<u8> = Vec::new();
+        input.extend_from_slice(&seed.to_be_bytes());
+        input.extend_from_slice(&self.data::<PetGameData>().salt.to_be_bytes());
+        let data = self.data::<PetGameData>();
+        data.salt = data.salt.wrapping_add(1);
+        Ok(keccak(&input))
+    }
+
+    /// Store the commitment of the pet owner to a secret.
+    /// A commitment can't be replaced until it was revealed, so outcomes can't be rerolled
+    default fn _commit_randomness(&mut self, token_id: &Id, commitment: Hash) -> Result<()> {
+        let data = self.data::<PetGameData>();
+        if data.commitments.contains(token_id) {
+            return Err(RmrkError::CommitmentPending.into())
+        }
+        let block_number = Self::env().block_number();
+        data.commitments
+            .insert(token_id, &(commitment, block_number));
+        Ok(())
+    }
+
+    /// Check the secret against the commitment and store the seed derived from it.
+    /// The reveal must happen in a later block than the commitment. The seed must be used
+    /// in the same message, as anyone can read it from the storage afterwards
+    default fn _reveal_randomness(&mut self, token_id: &Id, secret: &[u8]) -> Result<()> {
+        let (commitment, commit_block) = self
+            .data::<PetGameData>()
+            .commitments
+            .get(token_id)
+            .ok_or(RmrkError::CommitmentNotFound)?;
+        if Self::env().block_number() <= commit_block {
+            return Err(RmrkError::RevealTooEarly.into())
+        }
+        if Hash::from(keccak(secret)) != commitment {
+            return Err(RmrkError::InvalidReveal.into())
+        }
+
+        let mut input: Vec<u8> = Vec::new();
+        input.extend_from_slice(secret);
+        input.extend_from_slice(&commit_block.to_be_bytes());
+        input.extend_from_slice(&token_id.encode());
+        let data = self.data::<PetGameData>();
+        data.commitments.remove(token_id);
+        data.revealed_seeds.insert(token_id, &keccak(&input));
+        Ok(())
+    }
+
+    /// Consume the seed revealed by the pet owner in this message. It is mixed with the hash
+    /// of the block, which the owner didn't know when committing, and with VRF output if `vrf`
+    default fn _commit_reveal_randomness(&mut self, token_id: &Id, vrf: bool) -> Result<[u8; 32]> {
+        let data = self.data::<PetGameData>();
+        let seed = data
+            .revealed_seeds
+            .get(token_id)
+            .ok_or(RmrkError::RandomnessNotRevealed)?;
+        data.revealed_seeds.remove(token_id);
+
+        let mut input: Vec<u8> = Vec::new();
+        input.extend_from_slice(&seed);
+        input.extend_from_slice(&self._hash_randomness(token_id)?);
+        if vrf {
+            input.extend_from_slice(&self._vrf_randomness(token_id)?);
+        }
+        Ok(keccak(&input))
+    }
+
+    /// Request random bytes from the runtime VRF through the chain extension
+    default fn _vrf_randomness(&mut self, token_id: &Id) -> Result<[u8; 32]> {
+        let mut subject: Vec<u8> = Vec::new();
+        subject.extend_from_slice(&self.data::<PetGameData>().salt.to_be_bytes());
+        subject.extend_from_slice(&token_id.encode());
+        let data = self.data::<PetGameData>();
+        data.salt = data.salt.wrapping_add(1);
+
+        ChainExtensionMethod::build(VRF_FUNCTION_ID)
+            .input::<Vec<u8>>()
+            .output::<[u8; 32], false>()
+            .handle_error_code::<VrfUnavailable>()
+            .call(&subject)
+            .map_err(|_| RmrkError::RandomnessUnavailable.into())
+    }
+}
@@ -0,0 +1,178 @@
+//! RMRK PetGame rate limiting of player actions
+use crate::{
+    traits::RateLimit,
+    RateLimitData,
+};
+
+use rmrk_common::{
+    errors::{
+        Error,
+        Result,
+        RmrkError,
+    },
+    roles::ADMIN,
+    types::*,
+};
+
+use ink::prelude::string::String as PreludeString;
+
+use openbrush::{
+    contracts::access_control::*,
+    modifier_definition,
+    modifiers,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+/// Throttle the calls of the caller to `action` to its budget and count them towards the cap
+/// of the block. Actions without a budget are only subject to the cap.
+///
+/// The calls are counted before the body runs. If the body fails the whole message is reverted,
+/// so failed calls don't use the budget.
+#[modifier_definition]
+pub fn rate_limited<T, F, R, E>(
+    instance: &mut T,
+    body: F,
+    action: &str,
+) -> core::result::Result<R, E>
+where
+    T: Storage<RateLimitData>,
+    F: FnOnce(&mut T) -> core::result::Result<R, E>,
+    E: From<Error>,
+{
+    instance._consume_rate_limit(action.as_bytes())?;
+    body(instance)
+}
+
+/// Trait definitions for RateLimit helper functions
+pub trait RateLimitInternal {
+    /// Compute the start of the current window of the account for the action and the calls
+    /// it made in it
+    fn window_usage(
+        &self,
+        action: &[u8],
+        budget: &ActionBudget,
+        account_id: &AccountId,
+    ) -> (u64, u32);
+
+    /// Count a call of the caller to the action. Return error if it is over budget or the block
+    /// is full
+    fn _consume_rate_limit(&mut self, action: &[u8]) -> Result<()>;
+}
+
+/// Implement internal helper trait for RateLimit
+impl<T> RateLimitInternal for T
+where
+    T: Storage<RateLimitData>,
+{
+    default fn window_usage(
+        &self,
+        action: &[u8],
+        budget: &ActionBudget,
+        account_id: &AccountId,
+    ) -> (u64, u32) {
+        let (now, length) = match budget.window {
+            RateWindow::Blocks(blocks) => (Self::env().block_number() as u64, blocks as u64),
+            RateWindow::Time(duration) => (Self::env().block_timestamp(), duration),
+        };
+        match self
+            .data::<RateLimitData>()
+            .usage
+            .get((action.to_vec(), *account_id))
+        {
+            Some((window_start, calls)) if now < window_start.saturating_add(length) => {
+                (window_start, calls)
+            }
+            _ => (now, 0),
+        }
+    }
+
+    default fn _consume_rate_limit(&mut self, action: &[u8]) -> Result<()> {
+        let caller = Self::env().caller();
+        let block = Self::env().block_number();
+        let data = self.data::<RateLimitData>();
+        let (last_block, block_calls) = data.block_usage;
+        let block_calls = if last_block == block { block_calls } else { 0 };
+        if matches!(data.block_cap, Some(cap) if block_calls >= cap) {
+            return Err(RmrkError::BlockCapReached.into())
+        }
+
+        let budget = data.budgets.get(action.to_vec());
+        let usage = match budget {
+            Some(budget) => {
+                let (window_start, calls) = self.window_usage(action, &budget, &caller);
+                if calls >= budget.max_calls {
+                    return Err(RmrkError::RateLimited.into())
+                }
+                Some((window_start, calls + 1))
+            }
+            None => None,
+        };
+
+        let data = self.data::<RateLimitData>();
+        data.block_usage = (block, block_calls.saturating_add(1));
+        if let Some(usage) = usage {
+            data.usage.insert((action.to_vec(), caller), &usage);
+        }
+        Ok(())
+    }
+}
+
+impl<T> RateLimit for T
+where
+    T: Storage<RateLimitData> + Storage<access_control::Data>,
+{
+    /// Set the budget of each account for the action.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_action_budget(
+        &mut self,
+        action: PreludeString,
+        budget: Option<ActionBudget>,
+    ) -> Result<()> {
+        let budgets = &mut self.data::<RateLimitData>().budgets;
+        match budget {
+            Some(ActionBudget {
+                window: RateWindow::Blocks(0) | RateWindow::Time(0),
+                ..
+            }) => return Err(RmrkError::BadConfig.into()),
+            Some(budget) => {
+                budgets.insert(action.into_bytes(), &budget);
+            }
+            None => budgets.remove(action.into_bytes()),
+        }
+        Ok(())
+    }
+
+    /// Used to retrieve the budget of each account for the action.
+    default fn get_action_budget(&self, action: PreludeString) -> Option<ActionBudget> {
+        self.data::<RateLimitData>()
+            .budgets
+            .get(action.into_bytes())
+    }
+
+    /// Set the most rate limited calls all accounts can make in one block.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_block_cap(&mut self, cap: Option<u32>) -> Result<()> {
+        self.data::<RateLimitData>().block_cap = cap;
+        Ok(())
+    }
+
+    /// Used to retrieve the most rate limited calls all accounts can make in one block.
+    default fn get_block_cap(&self) -> Option<u32> {
+        self.data::<RateLimitData>().block_cap
+    }
+
+    /// Used to retrieve the calls the account has left for the action in its current window.
+    default fn get_remaining_calls(
+        &self,
+        action: PreludeString,
+        account_id: AccountId,
+    ) -> Option<u32> {
+        let action = action.into_bytes();
+        let budget = self.data::<RateLimitData>().budgets.get(&action)?;
+        let (_, calls) = self.window_usage(&action, &budget, &account_id);
+        Some(budget.max_calls.saturating_sub(calls))
+    }
+}
@@ -0,0 +1,150 @@
+//! RMRK PetGame seasons implementation
+use crate::{
+    collection::PetCollection,
+    internal::Internal,
+    staking::{
+        StakingInternal,
+        BASIS_POINTS,
+    },
+    traits::Seasons,
+    PetGameData,
+    RateLimitData,
+    StakingData,
+};
+
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::ADMIN,
+    types::*,
+};
+
+use openbrush::{
+    contracts::access_control::*,
+    modifiers,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+impl<T> Seasons for T
+where
+    T: Storage<PetGameData>
+        + Storage<StakingData>
+        + Storage<RateLimitData>
+        + Storage<access_control::Data>
+        + PetCollection,
+{
+    /// Used to retrieve the current season.
+    default fn get_season(&self) -> u32 {
+        self.data::<PetGameData>().season
+    }
+
+    /// End the current season and start a new one. Accounts keep the share of their balances
+    /// set by `carry_over`, nothing without it. Stakes are always kept while a PSP22 currency
+    /// is set, as the game holds the tokens of the accounts.
+    #[modifiers(only_role(ADMIN))]
+    default fn start_new_season(&mut self, carry_over: Option<CarryOver>) -> Result<()> {
+        let mut carry_over = carry_over.unwrap_or_default();
+        if carry_over.money > BASIS_POINTS || carry_over.items > BASIS_POINTS {
+            return Err(RmrkError::BadConfig.into())
+        }
+        if self.data::<PetGameData>().currency.is_some() {
+            carry_over.stakes = true;
+        }
+        let season = self.get_season();
+        let next_season = season.checked_add(1).ok_or(RmrkError::BadConfig)?;
+
+        // Stakes earn their rewards until the season ends. Balances are carried over when they
+        // are used in a later season, as the accounts can't be iterated
+        self._update_pool();
+        let data = self.data::<StakingData>();
+        data.season_rewards.insert(season, &data.reward_per_weight);
+        if !carry_over.stakes {
+            // The currency of the reset stakes pays future rewards
+            data.treasury = data.treasury.saturating_add(data.total_staked);
+            data.total_staked = 0;
+            data.total_weight = 0;
+        }
+        let index = self.season_index(season);
+        let data = self.data::<PetGameData>();
+        let (money, money_end) = carry_index_after(&index.money, carry_over.money);
+        if let Some(factor) = money_end {
+            data.money_epoch_ends.insert(index.money.epoch, &factor);
+        }
+        let (items, items_end) = carry_index_after(&index.items, carry_over.items);
+        if let Some(factor) = items_end {
+            data.items_epoch_ends.insert(index.items.epoch, &factor);
+        }
+        let mut stake_resets = index.stake_resets;
+        if !carry_over.stakes {
+            data.stake_reset_seasons.insert(stake_resets, &season);
+            stake_resets += 1;
+        }
+        data.season_indexes.insert(
+            next_season,
+            &SeasonIndex {
+                money,
+                items,
+                stake_resets,
+            },
+        );
+        data.carry_overs.insert(season, &carry_over);
+        data.season = next_season;
+        Ok(())
+    }
+
+    /// Used to retrieve the share of the balances kept when `season` ended.
+    default fn get_carry_over(&self, season: u32) -> Option<CarryOver> {
+        self.data::<PetGameData>().carry_overs.get(season)
+    }
+
+    /// Used to retrieve the in-game currency the account owned in `season`.
+    default fn get_season_money(&self, season: u32, account_id: AccountId) -> u64 {
+        if season > self.get_season() {
+            return 0
+        }
+        self.season_money(&account_id, season)
+    }
+
+    /// Used to retrieve the number of `item_id` the account owned in `season`.
+    default fn get_season_items(&self, season: u32, account_id: AccountId, item_id: ItemId) -> u32 {
+        if season > self.get_season() {
+            return 0
+        }
+        self.season_items(&account_id, item_id, season)
+    }
+
+    /// Used to retrieve the currency the account staked in `season`, over all its stakes.
+    default fn get_season_staked_money(&self, season: u32, account_id: AccountId) -> u64 {
+        if season > self.get_season() {
+            return 0
+        }
+        self.season_staked_money(&account_id, season)
+    }
+}
+
+/// Return the index after a season keeping `share` of the balances, along with the factor its
+/// epoch ended with if it starts a new one
+fn carry_index_after(index: &CarryIndex, share: u32) -> (CarryIndex, Option<u128>) {
+    let factor = index.factor * share as u128 / BASIS_POINTS as u128;
+    if factor >= MIN_CARRY_FACTOR {
+        return (
+            CarryIndex {
+                epoch: index.epoch,
+                factor,
+            },
+            None,
+        )
+    }
+    (
+        CarryIndex {
+            epoch: index.epoch + 1,
+            factor: CARRY_PRECISION,
+        },
+        Some(factor),
+    )
+}
@@ -0,0 +1,264 @@
+//! RMRK PetGame shop implementation
+use crate::{
+    collection::PetCollection,
+    internal::{
+        currency_error,
+        Internal,
+    },
+    rate_limit::rate_limited,
+    traits::{
+        PetGame,
+        PetGameEvents,
+        Shop,
+    },
+    PetGameData,
+    RateLimitData,
+    ShopData,
+};
+
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::ADMIN,
+    types::*,
+    utils::Utils,
+};
+
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    contracts::{
+        access_control::*,
+        traits::psp22::PSP22Ref,
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+
+/// Trait definitions for Shop helper functions
+pub trait ShopInternal {
+    /// Check that the item of the listing exists and that its sale doesn't end before it starts
+    fn ensure_valid_listing(&self, listing: &Listing) -> Result<()>;
+
+    /// Check if the listing can be bought now
+    fn is_sale_active(&self, listing: &Listing) -> bool;
+
+    /// Take `price` in the currency of the listing from the account
+    fn _charge_listing(
+        &mut self,
+        account_id: &AccountId,
+        currency: &ShopCurrency,
+        price: Balance,
+    ) -> Result<()>;
+}
+
+/// Implement internal helper trait for Shop
+impl<T> ShopInternal for T
+where
+    T: Storage<ShopData> + Storage<PetGameData> + PetGame + PetCollection,
+{
+    default fn ensure_valid_listing(&self, listing: &Listing) -> Result<()> {
+        self.get_item(listing.item_id)
+            .ok_or(RmrkError::ItemNotFound)?;
+        if matches!(listing.ends_at, Some(ends_at) if ends_at <= listing.starts_at) {
+            return Err(RmrkError::BadConfig.into())
+        }
+        Ok(())
+    }
+
+    default fn is_sale_active(&self, listing: &Listing) -> bool {
+        let current_time = Self::env().block_timestamp();
+        current_time >= listing.starts_at
+            && listing
+                .ends_at
+                .map_or(true, |ends_at| current_time < ends_at)
+    }
+
+    default fn _charge_listing(
+        &mut self,
+        account_id: &AccountId,
+        currency: &ShopCurrency,
+        price: Balance,
+    ) -> Result<()> {
+        // Value sent along with other currencies would be stuck in the contract
+        let transferred_value = Self::env().transferred_value();
+        match currency {
+            ShopCurrency::Native => {
+                if transferred_value != price {
+                    return Err(RmrkError::InvalidPayment.into())
+                }
+                Ok(())
+            }
+            _ if transferred_value != 0 => Err(RmrkError::InvalidPayment.into()),
+            // Ledger listings are paid from the internal ledger even while a PSP22 currency is set
+            ShopCurrency::Ledger => {
+                let price = u64::try_from(price).map_err(|_| RmrkError::NotEnoughMoney)?;
+                self._minus_your_money(account_id, price)
+            }
+            ShopCurrency::Psp22(token) => {
+                PSP22Ref::transfer_from(
+                    token,
+                    *account_id,
+                    Self::env().account_id(),
+                    price,
+                    Vec::new(),
+                )
+                .map_err(currency_error)
+            }
+        }
+    }
+}
+
+impl<T> Shop for T
+where
+    T: Storage<ShopData>
+        + Storage<PetGameData>
+        + Storage<RateLimitData>
+        + Storage<access_control::Data>
+        + PetCollection
+        + Utils,
+{
+    /// Put an item on sale. Return the id of the listing.
+    #[modifiers(only_role(ADMIN))]
+    default fn add_listing(&mut self, listing: Listing) -> Result<ListingId> {
+        self.ensure_valid_listing(&listing)?;
+        let listing_id = self
+            .data::<ShopData>()
+            .last_listing_id
+            .checked_add(1)
+            .ok_or(RmrkError::BadConfig)?;
+        let data = self.data::<ShopData>();
+        data.last_listing_id = listing_id;
+        data.listings.insert(listing_id, &listing);
+        Ok(listing_id)
+    }
+
+    /// Replace a listing.
+    #[modifiers(only_role(ADMIN))]
+    default fn update_listing(&mut self, listing_id: ListingId, listing: Listing) -> Result<()> {
+        self.get_listing(listing_id)
+            .ok_or(RmrkError::ListingNotFound)?;
+        self.ensure_valid_listing(&listing)?;
+        self.data::<ShopData>()
+            .listings
+            .insert(listing_id, &listing);
+        Ok(())
+    }
+
+    /// Take a listing off the shop.
+    #[modifiers(only_role(ADMIN))]
+    default fn remove_listing(&mut self, listing_id: ListingId) -> Result<()> {
+        self.get_listing(listing_id)
+            .ok_or(RmrkError::ListingNotFound)?;
+        self.data::<ShopData>().listings.remove(listing_id);
+        Ok(())
+    }
+
+    /// Used to retrieve a listing.
+    default fn get_listing(&self, listing_id: ListingId) -> Option<Listing> {
+        self.data::<ShopData>().listings.get(listing_id)
+    }
+
+    /// Used to retrieve the listings on sale now along with their ids, among at most `limit`
+    /// ids from `from`.
+    default fn get_active_listings(
+        &self,
+        from: ListingId,
+        limit: u32,
+    ) -> Vec<(ListingId, Listing)> {
+        (from.max(1)..=self.data::<ShopData>().last_listing_id)
+            .take(limit as usize)
+            .filter_map(|listing_id| {
+                self.get_listing(listing_id)
+                    .map(|listing| (listing_id, listing))
+            })
+            .filter(|(_, listing)| self.is_sale_active(listing))
+            .collect()
+    }
+
+    /// Buy `qty` items of the listing for the caller.
+    #[modifiers(rate_limited("buy"))]
+    default fn buy_listing(&mut self, listing_id: ListingId, qty: u32) -> Result<()> {
+        let caller = Self::env().caller();
+        let mut listing = self
+            .get_listing(listing_id)
+            .ok_or(RmrkError::ListingNotFound)?;
+        if !self.is_sale_active(&listing) {
+            return Err(RmrkError::SaleNotActive.into())
+        }
+        if let Some(stock) = listing.stock {
+            listing.stock = Some(stock.checked_sub(qty).ok_or(RmrkError::OutOfStock)?);
+        }
+        let purchased = self
+            .get_purchased(listing_id, caller)
+            .checked_add(qty)
+            .ok_or(RmrkError::PurchaseLimitReached)?;
+        if matches!(listing.limit_per_account, Some(limit) if purchased > limit) {
+            return Err(RmrkError::PurchaseLimitReached.into())
+        }
+        if self
+            .get_item_balance(caller, listing.item_id)
+            .checked_add(qty)
+            .is_none()
+        {
+            return Err(RmrkError::TooManyItems.into())
+        }
+        let price = listing
+            .price
+            .checked_mul(qty as Balance)
+            .ok_or(RmrkError::NotEnoughMoney)?;
+
+        self._charge_listing(&caller, &listing.currency, price)?;
+        let revenue = self
+            .get_shop_revenue(listing.currency)
+            .saturating_add(price);
+        let data = self.data::<ShopData>();
+        data.revenue.insert(listing.currency, &revenue);
+        data.purchased.insert((listing_id, caller), &purchased);
+        data.listings.insert(listing_id, &listing);
+        self._add_items(&caller, listing.item_id, qty)?;
+        self._emit_listing_bought_event(&caller, listing_id, qty, price);
+        Ok(())
+    }
+
+    /// Used to retrieve the number of items the account bought from the listing.
+    default fn get_purchased(&self, listing_id: ListingId, account_id: AccountId) -> u32 {
+        self.data::<ShopData>()
+            .purchased
+            .get((listing_id, account_id))
+            .unwrap_or_default()
+    }
+
+    /// Used to retrieve the revenue of the shop in `currency` not withdrawn yet.
+    default fn get_shop_revenue(&self, currency: ShopCurrency) -> Balance {
+        self.data::<ShopData>()
+            .revenue
+            .get(currency)
+            .unwrap_or_default()
+    }
+
+    /// Pay the revenue of the shop in `currency` to the caller. Return it.
+    #[modifiers(only_role(ADMIN))]
+    default fn withdraw_shop_revenue(&mut self, currency: ShopCurrency) -> Result<Balance> {
+        let caller = Self::env().caller();
+        let revenue = self.get_shop_revenue(currency);
+        self.data::<ShopData>().revenue.remove(currency);
+        match currency {
+            ShopCurrency::Native => self.withdraw()?,
+            ShopCurrency::Ledger => {
+                let revenue = u64::try_from(revenue).map_err(|_| RmrkError::MoneyOverflow)?;
+                self._plus_your_money(&caller, revenue)?
+            }
+            ShopCurrency::Psp22(token) => {
+                PSP22Ref::transfer(&token, caller, revenue, Vec::new()).map_err(currency_error)?
+            }
+        }
+        Ok(revenue)
+    }
+}
@@ -0,0 +1,355 @@
+//! RMRK PetGame staking implementation
+use crate::{
+    collection::PetCollection,
+    internal::Internal,
+    rate_limit::rate_limited,
+    traits::{
+        PetGame,
+        PetGameEvents,
+        Staking,
+    },
+    PetGameData,
+    RateLimitData,
+    StakingData,
+};
+
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::ADMIN,
+    types::*,
+};
+
+use openbrush::{
+    contracts::access_control::*,
+    modifiers,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+/// Basis points in a whole, which is also the boost of a stake without lock
+pub const BASIS_POINTS: u32 = 10_000;
+
+/// Length of a year in milliseconds, used to turn the APR into a rate
+pub const YEAR: u64 = 365 * 24 * 60 * 60 * 1000;
+
+/// Scale of the accumulated reward per weight
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+/// Trait definitions for Staking helper functions
+pub trait StakingInternal {
+    /// Compute the accumulated reward per weight as if the pool was updated now.
+    /// Return it along with the rewards it reserves from the treasury
+    fn _accrued_reward_per_weight(&self) -> (u128, u64);
+
+    /// Accrue the rewards of all stakes up to now, capped by the treasury
+    fn _update_pool(&mut self);
+
+    /// Account the rewards of the stake up to `reward_per_weight`
+    fn _settle(&self, position: &mut StakePosition, reward_per_weight: u128);
+
+    /// Reset the stake at the end of the seasons since it was stored that didn't keep the stakes.
+    /// Its rewards are accounted for up to the end of the season it was reset at
+    fn carried_position(&self, position: StakePosition) -> StakePosition;
+
+    /// Return the lock option for `lock_id`. `None` is the stake without lock
+    fn _lock_option(&self, lock_id: Option<u32>) -> Result<LockOption>;
+
+    /// Store the stake in the current season, removing it once it's empty
+    fn _set_position(
+        &mut self,
+        account_id: &AccountId,
+        lock_id: Option<u32>,
+        position: StakePosition,
+    );
+
+    /// Pay the unclaimed rewards of the stake to the account
+    fn _claim_rewards(&mut self, account_id: &AccountId, lock_id: Option<u32>) -> Result<u64>;
+}
+
+/// Implement internal helper trait for Staking
+impl<T> StakingInternal for T
+where
+    T: Storage<StakingData> + Storage<PetGameData> + PetGame + PetCollection,
+{
+    default fn _accrued_reward_per_weight(&self) -> (u128, u64) {
+        let data = self.data::<StakingData>();
+        let current_time = Self::env().block_timestamp();
+        if data.total_weight == 0 || current_time <= data.last_update {
+            return (data.reward_per_weight, 0)
+        }
+
+        let elapsed = (current_time - data.last_update) as u128;
+        let mut increment = (data.config.apr as u128)
+            .saturating_mul(elapsed)
+            .saturating_mul(REWARD_PRECISION)
+            / (BASIS_POINTS as u128 * BASIS_POINTS as u128 * YEAR as u128);
+        let mut reserved = data.total_weight.saturating_mul(increment) / REWARD_PRECISION;
+
+        // Rewards never exceed what the treasury holds
+        let treasury = data.treasury as u128;
+        if reserved > treasury {
+            increment = treasury.saturating_mul(REWARD_PRECISION) / data.total_weight;
+            reserved = data.total_weight.saturating_mul(increment) / REWARD_PRECISION;
+        }
+        (
+            data.reward_per_weight.saturating_add(increment),
+            reserved.min(treasury) as u64,
+        )
+    }
+
+    default fn _update_pool(&mut self) {
+        let (reward_per_weight, reserved) = self._accrued_reward_per_weight();
+        let data = self.data::<StakingData>();
+        data.reward_per_weight = reward_per_weight;
+        data.treasury -= reserved;
+        data.last_update = Self::env().block_timestamp();
+    }
+
+    default fn _settle(&self, position: &mut StakePosition, reward_per_weight: u128) {
+        let weight = position.amount as u128 * position.boost as u128;
+        let rewards = weight.saturating_mul(reward_per_weight - position.reward_per_weight_paid)
+            / REWARD_PRECISION;
+        position.pending_rewards = position
+            .pending_rewards
+            .saturating_add(u64::try_from(rewards).unwrap_or(u64::MAX));
+        position.reward_per_weight_paid = reward_per_weight;
+    }
+
+    default fn carried_position(&self, mut position: StakePosition) -> StakePosition {
+        let season = self.data::<PetGameData>().season;
+        let resets = self.season_index(position.season).stake_resets;
+        if resets != self.season_index(season).stake_resets {
+            // Only the first reset since the stake was stored matters, it's empty after it
+            let ended = self
+                .data::<PetGameData>()
+                .stake_reset_seasons
+                .get(resets)
+                .unwrap_or_default();
+            let reward_per_weight = self
+                .data::<StakingData>()
+                .season_rewards
+                .get(ended)
+                .unwrap_or_default();
+            self._settle(&mut position, reward_per_weight);
+            position.amount = 0;
+        }
+        position.season = season;
+        position
+    }
+
+    default fn _lock_option(&self, lock_id: Option<u32>) -> Result<LockOption> {
+        match lock_id {
+            None => {
+                Ok(LockOption {
+                    duration: 0,
+                    boost: BASIS_POINTS,
+                })
+            }
+            Some(lock_id) => {
+                self.data::<StakingData>()
+                    .config
+                    .lock_options
+                    .get(lock_id as usize)
+                    .cloned()
+                    .ok_or(RmrkError::InvalidLockId.into())
+            }
+        }
+    }
+
+    default fn _set_position(
+        &mut self,
+        account_id: &AccountId,
+        lock_id: Option<u32>,
+        mut position: StakePosition,
+    ) {
+        position.season = self.data::<PetGameData>().season;
+        let positions = &mut self.data::<StakingData>().positions;
+        if position.amount == 0 && position.pending_rewards == 0 {
+            positions.remove((account_id, lock_id));
+        } else {
+            positions.insert((account_id, lock_id), &position);
+        }
+    }
+
+    default fn _claim_rewards(
+        &mut self,
+        account_id: &AccountId,
+        lock_id: Option<u32>,
+    ) -> Result<u64> {
+        self._update_pool();
+        let mut position = self
+            .get_stake(*account_id, lock_id)
+            .ok_or(RmrkError::NotEnoughStake)?;
+        let reward_per_weight = self.data::<StakingData>().reward_per_weight;
+        self._settle(&mut position, reward_per_weight);
+        let rewards = position.pending_rewards;
+        position.pending_rewards = 0;
+        self._set_position(account_id, lock_id, position);
+        self._pay_money(account_id, rewards)?;
+        self._emit_staking_rewards_claimed_event(account_id, lock_id, rewards);
+        Ok(rewards)
+    }
+}
+
+impl<T> Staking for T
+where
+    T: Storage<StakingData>
+        + Storage<PetGameData>
+        + Storage<RateLimitData>
+        + Storage<access_control::Data>
+        + PetCollection,
+{
+    /// Set the APR and the lock options of staking.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_staking_config(&mut self, config: StakingConfig) -> Result<()> {
+        if config.lock_options.iter().any(|option| option.boost == 0) {
+            return Err(RmrkError::BadConfig.into())
+        }
+        // Rewards accrued so far keep the previous APR
+        self._update_pool();
+        self.data::<StakingData>().config = config;
+        Ok(())
+    }
+
+    /// Used to retrieve the APR and the lock options of staking.
+    default fn get_staking_config(&self) -> StakingConfig {
+        self.data::<StakingData>().config.clone()
+    }
+
+    /// Add `amount` of the caller's currency to the treasury paying the staking rewards.
+    #[modifiers(rate_limited("stake"))]
+    default fn fund_staking_treasury(&mut self, amount: u64) -> Result<()> {
+        self._update_pool();
+        let treasury = self
+            .data::<StakingData>()
+            .treasury
+            .checked_add(amount)
+            .ok_or(RmrkError::MoneyOverflow)?;
+        self._charge_money(&Self::env().caller(), amount)?;
+        self.data::<StakingData>().treasury = treasury;
+        Ok(())
+    }
+
+    /// Used to retrieve the currency left in the treasury for future rewards.
+    default fn get_staking_treasury(&self) -> u64 {
+        let (_, reserved) = self._accrued_reward_per_weight();
+        self.data::<StakingData>().treasury - reserved
+    }
+
+    /// Used to retrieve the currency staked by all accounts.
+    default fn get_total_staked(&self) -> u64 {
+        self.data::<StakingData>().total_staked
+    }
+
+    /// Stake `amount` of the caller's currency with the lock option `lock_id`.
+    #[modifiers(rate_limited("stake"))]
+    default fn stake(&mut self, amount: u64, lock_id: Option<u32>) -> Result<()> {
+        let caller = Self::env().caller();
+        let lock_option = self._lock_option(lock_id)?;
+        self._update_pool();
+
+        let mut position = self.get_stake(caller, lock_id).unwrap_or_default();
+        let reward_per_weight = self.data::<StakingData>().reward_per_weight;
+        self._settle(&mut position, reward_per_weight);
+        let total_staked = self
+            .data::<StakingData>()
+            .total_staked
+            .checked_add(amount)
+            .ok_or(RmrkError::MoneyOverflow)?;
+        let previous_weight = position.amount as u128 * position.boost as u128;
+        position.amount = position
+            .amount
+            .checked_add(amount)
+            .ok_or(RmrkError::MoneyOverflow)?;
+        self._charge_money(&caller, amount)?;
+
+        position.boost = lock_option.boost;
+        let unlock_at = Self::env()
+            .block_timestamp()
+            .saturating_add(lock_option.duration);
+        position.unlock_at = position.unlock_at.max(unlock_at);
+
+        let data = self.data::<StakingData>();
+        data.total_weight =
+            data.total_weight - previous_weight + position.amount as u128 * position.boost as u128;
+        data.total_staked = total_staked;
+        self._set_position(&caller, lock_id, position);
+        let staked_money = self.get_staked_money(caller).saturating_add(amount);
+        self._set_staked_money(&caller, staked_money);
+        self._emit_staked_event(&caller, lock_id, amount);
+        Ok(())
+    }
+
+    /// Withdraw `amount` of the caller's stake with the lock option `lock_id` and claim its rewards.
+    #[modifiers(rate_limited("stake"))]
+    default fn unstake(&mut self, amount: u64, lock_id: Option<u32>) -> Result<()> {
+        let caller = Self::env().caller();
+        self._update_pool();
+
+        let mut position = self
+            .get_stake(caller, lock_id)
+            .ok_or(RmrkError::NotEnoughStake)?;
+        if Self::env().block_timestamp() < position.unlock_at {
+            return Err(RmrkError::StakeLocked.into())
+        }
+        if position.amount < amount {
+            return Err(RmrkError::NotEnoughStake.into())
+        }
+        let reward_per_weight = self.data::<StakingData>().reward_per_weight;
+        self._settle(&mut position, reward_per_weight);
+
+        let data = self.data::<StakingData>();
+        data.total_weight -= amount as u128 * position.boost as u128;
+        data.total_staked -= amount;
+        position.amount -= amount;
+        let rewards = position.pending_rewards;
+        let payout = amount.saturating_add(rewards);
+        position.pending_rewards = 0;
+        self._set_position(&caller, lock_id, position);
+        let staked_money = self.get_staked_money(caller).saturating_sub(amount);
+        self._set_staked_money(&caller, staked_money);
+        self._pay_money(&caller, payout)?;
+        self._emit_unstaked_event(&caller, lock_id, amount, rewards);
+        Ok(())
+    }
+
+    /// Claim the rewards of the caller's stake with the lock option `lock_id`.
+    #[modifiers(rate_limited("claim"))]
+    default fn claim_staking_rewards(&mut self, lock_id: Option<u32>) -> Result<u64> {
+        self._claim_rewards(&Self::env().caller(), lock_id)
+    }
+
+    /// Used to retrieve the stake of the account with the lock option `lock_id`.
+    default fn get_stake(
+        &self,
+        account_id: AccountId,
+        lock_id: Option<u32>,
+    ) -> Option<StakePosition> {
+        self.data::<StakingData>()
+            .positions
+            .get((account_id, lock_id))
+            .map(|position| self.carried_position(position))
+            .filter(|position| position.amount > 0 || position.pending_rewards > 0)
+    }
+
+    /// Used to retrieve the unclaimed rewards of the stake.
+    default fn get_pending_staking_rewards(
+        &self,
+        account_id: AccountId,
+        lock_id: Option<u32>,
+    ) -> u64 {
+        match self.get_stake(account_id, lock_id) {
+            None => 0,
+            Some(mut position) => {
+                let (reward_per_weight, _) = self._accrued_reward_per_weight();
+                self._settle(&mut position, reward_per_weight);
+                position.pending_rewards
+            }
+        }
+    }
+}
@@ -0,0 +1,910 @@
+//! Trait definitions for PetGame module
+use rmrk_common::{
+    errors::Result,
+    types::*,
+};
+
+use ink::prelude::{
+    string::String as PreludeString,
+    vec::Vec,
+};
+use openbrush::{
+    contracts::psp34::Id,
+    traits::{
+        AccountId,
+        Balance,
+        Hash,
+        String,
+    },
+};
+
+#[openbrush::wrapper]
+pub type PetGameRef = dyn PetGame;
+
+/// Trait definitions for PetGame ink! messages
+#[openbrush::trait_definition]
+pub trait PetGame {
+    /// Set the default condition uris and the starting items for `account_id`.
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn set_default(&mut self, account_id: AccountId) -> Result<()>;
+
+    /// Overwrite the status of the pet.
+    /// Fails with `StatOutOfRange` if a stat is above the configured maximum.
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn set_status(&mut self, token_id: Id, hungry: u32, health: u32, happy: u32) -> Result<()>;
+
+    /// Fully restore the pet.
+    /// Fails with `StatOutOfRange` if a stat of the full status is above the configured maximum.
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn set_full_status(&mut self, token_id: Id) -> Result<()>;
+
+    /// Drop the pet's health and happiness to zero.
+    /// Fails with `StatOutOfRange` if a stat of the death status is above the configured maximum.
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn set_death_status(&mut self, token_id: Id) -> Result<()>;
+
+    /// Improve every stat of the pet by 50.
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn set_lucky_status(&mut self, token_id: Id) -> Result<()>;
+
+    /// Used to retrieve the stored status of the pet.
+    #[ink(message)]
+    fn get_status(&self, token_id: Id) -> Option<Status>;
+
+    /// Used to retrieve the status of the pet once the elapsed time is taken into account.
+    #[ink(message)]
+    fn get_current_status(&self, token_id: Id) -> Option<Status>;
+
+    /// Persist the decayed status of the pet.
+    /// Every message changing the status does this first, but anyone may call it.
+    #[ink(message)]
+    fn checkpoint(&mut self, token_id: Id) -> Result<()>;
+
+    /// Used to retrieve the time up to which decay is included in the stored status.
+    #[ink(message)]
+    fn get_last_checkpoint(&self, token_id: Id) -> u64;
+
+    /// Set how the status of the pets decays over time.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_decay_config(&mut self, config: DecayConfig) -> Result<()>;
+
+    /// Used to retrieve how the status of the pets decays over time.
+    #[ink(message)]
+    fn get_decay_config(&self) -> DecayConfig;
+
+    /// Set the range the stats of the pets are kept in.
+    /// Stats beyond the new maximum are clamped the next time they are read.
+    /// Fails with `BadConfig` if the maximum is 0.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_stat_bounds(&mut self, bounds: StatBounds) -> Result<()>;
+
+    /// Used to retrieve the range the stats of the pets are kept in.
+    #[ink(message)]
+    fn get_stat_bounds(&self) -> StatBounds;
+
+    /// Set the weighted outcomes of feeding.
+    /// Fails with `BadConfig` if the weights sum to zero or overflow.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_feed_outcomes(&mut self, outcomes: Vec<FeedOutcome>) -> Result<()>;
+
+    /// Used to retrieve the weighted outcomes of feeding.
+    #[ink(message)]
+    fn get_feed_outcomes(&self) -> Vec<FeedOutcome>;
+
+    /// Used to retrieve the chance of each outcome of feeding in basis points,
+    /// in the order of `get_feed_outcomes`.
+    #[ink(message)]
+    fn get_feed_odds(&self) -> Vec<u32>;
+
+    /// Set where the outcomes of feeding are drawn from.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_randomness_kind(&mut self, kind: RandomnessKind) -> Result<()>;
+
+    /// Used to retrieve where the outcomes of feeding are drawn from.
+    #[ink(message)]
+    fn get_randomness_kind(&self) -> RandomnessKind;
+
+    /// Commit to the Keccak256 hash of a secret used for the next feeding of the pet.
+    /// Only used with `RandomnessKind::CommitReveal`, where pets are fed through
+    /// `reveal_randomness`. The caller must own the pet.
+    #[ink(message)]
+    fn commit_randomness(&mut self, token_id: Id, commitment: Hash) -> Result<()>;
+
+    /// Reveal the secret committed for the pet, in a later block than the commitment, and use
+    /// `item_id` on the pet in the same call. The roll also depends on the block of the call,
+    /// so the secret alone doesn't decide the outcome. The caller must own the pet.
+    #[ink(message)]
+    fn reveal_randomness(&mut self, token_id: Id, secret: Vec<u8>, item_id: ItemId) -> Result<()>;
+
+    /// Used to retrieve the stage of life of the pet at the current time.
+    #[ink(message)]
+    fn get_lifecycle_stage(&self, token_id: Id) -> LifecycleStage;
+
+    /// Used to retrieve the time the pet was first looked after. 0 if it never was.
+    #[ink(message)]
+    fn get_born_at(&self, token_id: Id) -> u64;
+
+    /// Set when the pets move to their next stage of life.
+    /// Ages must not decrease from one stage to the next.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_lifecycle_config(&mut self, config: LifecycleConfig) -> Result<()>;
+
+    /// Used to retrieve when the pets move to their next stage of life.
+    #[ink(message)]
+    fn get_lifecycle_config(&self) -> LifecycleConfig;
+
+    /// Set the collection asset rendered for pets in `stage`. `None` keeps their assets as they are.
+    /// When a pet enters the stage, the asset is added to it and given the highest priority.
+    /// Requires the `CONTRIBUTOR` role.
+    #[ink(message)]
+    fn set_stage_asset(&mut self, stage: LifecycleStage, asset_id: Option<AssetId>) -> Result<()>;
+
+    /// Used to retrieve the collection asset rendered for pets in `stage`.
+    #[ink(message)]
+    fn get_stage_asset(&self, stage: LifecycleStage) -> Option<AssetId>;
+
+    /// Improve every stat of the pet by 20. Stats saturate at their bounds.
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn add_twenty(&mut self, token_id: Id) -> Result<()>;
+
+    /// Improve every stat of the pet by `number`. Stats saturate at their bounds.
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn change_some_status(&mut self, token_id: Id, number: u32) -> Result<()>;
+
+    /// Set the uri used while the pet is in a normal condition.
+    #[ink(message)]
+    fn set_normal_uri(&mut self, normal_uri: String) -> Result<()>;
+
+    #[ink(message)]
+    fn get_normal_uri(&self) -> String;
+
+    /// Set the uri used while the pet is in a good condition.
+    #[ink(message)]
+    fn set_good_uri(&mut self, good_uri: String) -> Result<()>;
+
+    #[ink(message)]
+    fn get_good_uri(&self) -> String;
+
+    /// Set the uri used while the pet is in a bad condition.
+    #[ink(message)]
+    fn set_bad_uri(&mut self, bad_uri: String) -> Result<()>;
+
+    #[ink(message)]
+    fn get_bad_uri(&self) -> String;
+
+    /// Used to retrieve the sum of health and happiness minus hunger.
+    #[ink(message)]
+    fn get_total_status(&self, token_id: Id) -> u32;
+
+    /// Used to retrieve the condition of the pet from its total status.
+    #[ink(message)]
+    fn get_condition(&self, token_id: Id) -> Condition;
+
+    /// Used to retrieve the index of the condition tier of the pet from the lowest one.
+    /// Without condition tiers 0 is bad, 1 is normal and 2 is good.
+    #[ink(message)]
+    fn get_condition_tier(&self, token_id: Id) -> u32;
+
+    /// Used to retrieve the mood of the pet from its current status.
+    #[ink(message)]
+    fn get_mood(&self, token_id: Id) -> Mood;
+
+    /// Used to retrieve the status, condition, mood, stage of life, last meal and uri of the pet.
+    /// Return `None` if the pet doesn't exist.
+    #[ink(message)]
+    fn get_pet_view(&self, token_id: Id) -> Option<PetView>;
+
+    /// Used to retrieve the uri matching the condition of the pet.
+    /// Without condition tiers it is the base uri of the condition.
+    #[ink(message)]
+    fn get_condition_url(&self, token_id: Id) -> String;
+
+    /// Set the tiers of total status mapped to collection assets, by increasing threshold.
+    /// Tiers sharing a threshold offer several assets for the same condition.
+    /// Requires the `CONTRIBUTOR` role.
+    #[ink(message)]
+    fn set_condition_tiers(&mut self, tiers: Vec<ConditionTier>) -> Result<()>;
+
+    /// Used to retrieve the tiers of total status mapped to collection assets.
+    #[ink(message)]
+    fn get_condition_tiers(&self) -> Vec<ConditionTier>;
+
+    /// Used to retrieve the asset rendered for the condition of the pet.
+    /// The accepted asset of the tier with the highest priority wins, or the first asset of the tier.
+    #[ink(message)]
+    fn get_condition_asset(&self, token_id: Id) -> Option<AssetId>;
+
+    /// Feed an apple owned by `account_id` to the pet.
+    /// The outcome is drawn from the weighted outcome table.
+    /// The caller must own both the pet and `account_id`.
+    /// Rate limited as the `feed` action.
+    #[ink(message)]
+    fn eat_an_apple(&mut self, token_id: Id, account_id: AccountId) -> Result<()>;
+
+    /// Register a new item. Return its id.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn add_item(&mut self, item: Item) -> Result<ItemId>;
+
+    /// Replace a registered item. The apple is `APPLE_ITEM_ID`.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn update_item(&mut self, item_id: ItemId, item: Item) -> Result<()>;
+
+    /// Used to retrieve a registered item.
+    #[ink(message)]
+    fn get_item(&self, item_id: ItemId) -> Option<Item>;
+
+    /// Used to retrieve the id of the last registered item.
+    #[ink(message)]
+    fn get_last_item_id(&self) -> ItemId;
+
+    /// Used to retrieve the number of `item_id` owned by the account.
+    #[ink(message)]
+    fn get_item_balance(&self, account_id: AccountId, item_id: ItemId) -> u32;
+
+    /// Used to retrieve the last time `item_id` was used on the pet.
+    #[ink(message)]
+    fn get_item_last_used(&self, token_id: Id, item_id: ItemId) -> u64;
+
+    /// Set the duration of a cooldown in milliseconds.
+    /// Item cooldowns are part of the item and fail with `BadConfig`.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_cooldown(&mut self, cooldown: Cooldown, duration: u64) -> Result<()>;
+
+    /// Used to retrieve the duration of a cooldown in milliseconds.
+    #[ink(message)]
+    fn get_cooldown(&self, cooldown: Cooldown) -> u64;
+
+    /// Used to retrieve the last time the cooldown started for the token or account.
+    #[ink(message)]
+    fn get_last_used(&self, cooldown: Cooldown, subject: CooldownSubject) -> u64;
+
+    /// Used to retrieve the milliseconds left before the cooldown is over for the token or account.
+    #[ink(message)]
+    fn get_cooldown_remaining(&self, cooldown: Cooldown, subject: CooldownSubject) -> u64;
+
+    /// Buy `qty` of `item_id` with the in-game currency of the caller.
+    /// Rate limited as the `buy` action.
+    #[ink(message)]
+    fn buy_item(&mut self, item_id: ItemId, qty: u32) -> Result<()>;
+
+    /// Use one `item_id` owned by the caller on the pet.
+    /// Dead pets only accept items reviving them, which fail with `PetIsNotDead` on living pets.
+    /// Fails with `TimeHasNotPassed` until the cooldown of the item on this pet has passed,
+    /// and for items rolling the feeding outcome table until the `Feed` cooldown has passed.
+    /// The caller must own the pet.
+    /// Rate limited as the `feed` action.
+    #[ink(message)]
+    fn use_item(&mut self, token_id: Id, item_id: ItemId) -> Result<()>;
+
+    /// Used to retrieve the uri of the pet for its current condition.
+    /// Without condition tiers it is the base uri of the condition followed by the token id.
+    #[ink(message)]
+    fn token_uri(&self, token_id: Id) -> String;
+
+    #[ink(message)]
+    fn get_your_apple(&self, account_id: AccountId) -> u16;
+
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn set_your_apple(&mut self, account_id: AccountId, after_apple: u16) -> Result<()>;
+
+    /// Used to retrieve the in-game currency owned by the account.
+    /// With a PSP22 currency this is the token balance of the account.
+    #[ink(message)]
+    fn get_your_money(&self, account_id: AccountId) -> u64;
+
+    /// Set the PSP22 contract used as the in-game currency, or `None` to use the internal ledger.
+    /// Purchases and stakes use `transfer_from`, so players must approve the game first.
+    /// Bonuses are minted, so the game must be allowed to mint.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_currency(&mut self, currency: Option<AccountId>) -> Result<()>;
+
+    /// Used to retrieve the PSP22 contract used as the in-game currency.
+    #[ink(message)]
+    fn get_currency(&self) -> Option<AccountId>;
+
+    /// Only changes the internal ledger.
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn set_your_money(&mut self, account_id: AccountId, after_money: u64) -> Result<()>;
+
+    /// Buy one apple with the in-game currency.
+    /// The caller must be `account_id`.
+    #[ink(message)]
+    fn buy_an_apple(&mut self, account_id: AccountId) -> Result<()>;
+
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn minus_your_apple(&mut self, account_id: AccountId) -> Result<()>;
+
+    /// Only changes the internal ledger.
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn minus_your_money(&mut self, account_id: AccountId, change_money: u64) -> Result<()>;
+
+    /// Only changes the internal ledger.
+    /// Fails with `MoneyOverflow` if the balance would overflow.
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn plus_your_money(&mut self, account_id: AccountId, change_money: u64) -> Result<()>;
+
+    /// Credit the daily bonus of the current streak day to the caller.
+    /// Fails with `TimeHasNotPassed` until the `DailyBonus` cooldown has passed.
+    /// The streak restarts if the claim window after the cooldown was missed.
+    /// The caller must be `account_id`.
+    /// Rate limited as the `daily_bonus` action.
+    #[ink(message)]
+    fn daily_bonus(&mut self, account_id: AccountId) -> Result<()>;
+
+    /// Set the rewards of the daily bonus streak and its claim window.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_bonus_config(&mut self, config: BonusConfig) -> Result<()>;
+
+    /// Used to retrieve the rewards of the daily bonus streak and its claim window.
+    #[ink(message)]
+    fn get_bonus_config(&self) -> BonusConfig;
+
+    /// Used to retrieve the current daily bonus streak of the account. 0 once it was broken.
+    #[ink(message)]
+    fn get_bonus_streak(&self, account_id: AccountId) -> u32;
+
+    /// Used to retrieve the reward of the next daily bonus claim of the account.
+    #[ink(message)]
+    fn get_next_bonus(&self, account_id: AccountId) -> BonusReward;
+
+    #[ink(message)]
+    fn get_last_eaten(&self, token_id: Id) -> u64;
+
+    /// Used to retrieve the number of times the account fed its pets.
+    #[ink(message)]
+    fn get_feed_count(&self, account_id: AccountId) -> u32;
+
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn set_last_eaten(&mut self, token_id: Id, current_time: u64) -> Result<()>;
+
+    #[ink(message)]
+    fn get_last_bonus(&self, account_id: AccountId) -> u64;
+
+    /// Requires the `GAME_MASTER` role.
+    #[ink(message)]
+    fn set_last_bonus(&mut self, account_id: AccountId, current_time: u64) -> Result<()>;
+
+    /// Used to retrieve the currency staked by the account over all its stakes.
+    #[ink(message)]
+    fn get_staked_money(&self, account_id: AccountId) -> u64;
+
+    /// Used to retrieve the ranked entries of a leaderboard from `offset`, at most `limit` of them.
+    /// Ranks start at 1. Pets are ranked when they are checkpointed, players with their pets.
+    #[ink(message)]
+    fn get_leaderboard(
+        &self,
+        kind: LeaderboardKind,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(u32, LeaderboardEntry)>;
+
+    /// Used to retrieve the ranked entries a leaderboard had when `season` ended.
+    #[ink(message)]
+    fn get_leaderboard_snapshot(
+        &self,
+        season: u32,
+        kind: LeaderboardKind,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(u32, LeaderboardEntry)>;
+
+    /// Used to retrieve the season of the current leaderboards.
+    #[ink(message)]
+    fn get_leaderboard_season(&self) -> u32;
+
+    /// Set the number of entries kept on each leaderboard.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_leaderboard_size(&mut self, size: u32) -> Result<()>;
+
+    /// Used to retrieve the number of entries kept on each leaderboard.
+    #[ink(message)]
+    fn get_leaderboard_size(&self) -> u32;
+
+    /// Keep the current leaderboards as the snapshot of the season and start a new one.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn reset_leaderboards(&mut self) -> Result<()>;
+
+    /// Set the number of entries kept in the status history of each pet.
+    /// Lowering it drops the oldest entries from the histories, raising it again doesn't bring
+    /// them back. Dropped entries are removed from storage the next time the pet's history grows.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_status_history_capacity(&mut self, capacity: u32) -> Result<()>;
+
+    /// Used to retrieve the number of entries kept in the status history of each pet.
+    #[ink(message)]
+    fn get_status_history_capacity(&self) -> u32;
+
+    /// Used to retrieve the number of entries ever added to the status history of the pet.
+    /// The sequence number of the next entry.
+    #[ink(message)]
+    fn get_status_history_len(&self, token_id: Id) -> u32;
+
+    /// Used to retrieve the entries of the status history of the pet along with their sequence
+    /// number, from `from`, at most `limit` of them, oldest first.
+    /// Entries are added each time the stored status changes, including at checkpoints.
+    /// Entries dropped from the history are skipped.
+    #[ink(message)]
+    fn get_status_history(&self, token_id: Id, from: u32, limit: u32) -> Vec<(u32, StatusRecord)>;
+
+    /// Check if the caller owns `token_id`.
+    #[ink(message)]
+    fn is_nft_owner(&self, token_id: Id) -> bool;
+
+    /// Check if the caller is `account_id`.
+    #[ink(message)]
+    fn is_account_id(&self, account_id: AccountId) -> bool;
+}
+
+#[openbrush::wrapper]
+pub type InteractionsRef = dyn Interactions;
+
+/// Trait definitions for the interactions between pets
+#[openbrush::trait_definition]
+pub trait Interactions {
+    /// Play with another pet, which makes both happier.
+    /// Without owning the other pet, this only records the consent of the caller
+    /// until the other owner plays back. Return whether the pets played.
+    /// Fails with `TimeHasNotPassed` until the `Play` cooldown of both pets has passed.
+    /// The caller must own `token_id`.
+    #[ink(message)]
+    fn play_with(&mut self, token_id: Id, other_token_id: Id) -> Result<bool>;
+
+    /// Breed two adult pets. The child is minted, draws each stat from one of its parents
+    /// and is nested into `parent_id` until its owner accepts it.
+    /// Without owning the other parent, this only records the consent of the caller
+    /// until the other owner breeds back. Return the id of the child once it is born.
+    /// Fails with `TimeHasNotPassed` until the `Breed` cooldown of both parents has passed.
+    /// The caller must own `parent_id`.
+    #[ink(message)]
+    fn breed(&mut self, parent_id: Id, other_parent_id: Id) -> Result<Option<Id>>;
+
+    /// Withdraw the consent of the caller to an interaction with the other pet.
+    /// The caller must own `token_id`.
+    #[ink(message)]
+    fn withdraw_consent(
+        &mut self,
+        interaction: PetInteraction,
+        token_id: Id,
+        other_token_id: Id,
+    ) -> Result<()>;
+
+    /// Check if the owner of `token_id` agreed to the interaction with the other pet.
+    /// Consents given by a previous owner of the pet don't count.
+    #[ink(message)]
+    fn has_consent(&self, interaction: PetInteraction, token_id: Id, other_token_id: Id) -> bool;
+
+    /// Set the effect of playing on both pets.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_play_effect(&mut self, effect: StatusDelta) -> Result<()>;
+
+    /// Used to retrieve the effect of playing on both pets.
+    #[ink(message)]
+    fn get_play_effect(&self) -> StatusDelta;
+}
+
+#[openbrush::wrapper]
+pub type StakingRef = dyn Staking;
+
+/// Trait definitions for Staking ink! messages
+#[openbrush::trait_definition]
+pub trait Staking {
+    /// Set the APR and the lock options of staking.
+    /// Fails with `BadConfig` if a lock option has no boost.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_staking_config(&mut self, config: StakingConfig) -> Result<()>;
+
+    /// Used to retrieve the APR and the lock options of staking.
+    #[ink(message)]
+    fn get_staking_config(&self) -> StakingConfig;
+
+    /// Add `amount` of the caller's currency to the treasury paying the staking rewards.
+    /// Fails with `MoneyOverflow` if the treasury would overflow.
+    #[ink(message)]
+    fn fund_staking_treasury(&mut self, amount: u64) -> Result<()>;
+
+    /// Used to retrieve the currency left in the treasury for future rewards.
+    /// Rewards stop accruing once it's empty.
+    #[ink(message)]
+    fn get_staking_treasury(&self) -> u64;
+
+    /// Used to retrieve the currency staked by all accounts.
+    #[ink(message)]
+    fn get_total_staked(&self) -> u64;
+
+    /// Stake `amount` of the caller's currency with the lock option `lock_id`.
+    /// `None` stakes without lock. Staking more extends the lock of the whole stake.
+    /// Fails with `MoneyOverflow` if the staked total would overflow.
+    #[ink(message)]
+    fn stake(&mut self, amount: u64, lock_id: Option<u32>) -> Result<()>;
+
+    /// Withdraw `amount` of the caller's stake with the lock option `lock_id` and claim its rewards.
+    /// Fails with `StakeLocked` until the lock ends.
+    #[ink(message)]
+    fn unstake(&mut self, amount: u64, lock_id: Option<u32>) -> Result<()>;
+
+    /// Claim the rewards of the caller's stake with the lock option `lock_id`. Return them.
+    #[ink(message)]
+    fn claim_staking_rewards(&mut self, lock_id: Option<u32>) -> Result<u64>;
+
+    /// Used to retrieve the stake of the account with the lock option `lock_id`.
+    #[ink(message)]
+    fn get_stake(&self, account_id: AccountId, lock_id: Option<u32>) -> Option<StakePosition>;
+
+    /// Used to retrieve the unclaimed rewards of the stake.
+    #[ink(message)]
+    fn get_pending_staking_rewards(&self, account_id: AccountId, lock_id: Option<u32>) -> u64;
+}
+
+#[openbrush::wrapper]
+pub type ShopRef = dyn Shop;
+
+/// Trait definitions for Shop ink! messages
+#[openbrush::trait_definition]
+pub trait Shop {
+    /// Put an item on sale. Return the id of the listing.
+    /// Fails with `ItemNotFound` for unknown items and `BadConfig` if the sale ends before it starts.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn add_listing(&mut self, listing: Listing) -> Result<ListingId>;
+
+    /// Replace a listing. Purchases made so far still count towards the limit per account.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn update_listing(&mut self, listing_id: ListingId, listing: Listing) -> Result<()>;
+
+    /// Take a listing off the shop.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn remove_listing(&mut self, listing_id: ListingId) -> Result<()>;
+
+    /// Used to retrieve a listing.
+    #[ink(message)]
+    fn get_listing(&self, listing_id: ListingId) -> Option<Listing>;
+
+    /// Used to retrieve the listings on sale now along with their ids.
+    /// Only the ids from `from` are looked at, at most `limit` of them. Ids start at 1.
+    #[ink(message)]
+    fn get_active_listings(&self, from: ListingId, limit: u32) -> Vec<(ListingId, Listing)>;
+
+    /// Buy `qty` items of the listing for the caller.
+    /// Native listings must be paid with the exact value transferred, other listings without value.
+    /// PSP22 listings require the caller to approve the game first.
+    /// Fails with `SaleNotActive`, `OutOfStock` or `PurchaseLimitReached` when the listing doesn't allow it.
+    #[ink(message, payable)]
+    fn buy_listing(&mut self, listing_id: ListingId, qty: u32) -> Result<()>;
+
+    /// Used to retrieve the number of items the account bought from the listing.
+    #[ink(message)]
+    fn get_purchased(&self, listing_id: ListingId, account_id: AccountId) -> u32;
+
+    /// Used to retrieve the revenue of the shop in `currency` not withdrawn yet.
+    #[ink(message)]
+    fn get_shop_revenue(&self, currency: ShopCurrency) -> Balance;
+
+    /// Pay the revenue of the shop in `currency` to the caller. Return it.
+    /// The native revenue is paid through `Utils::withdraw` with the rest of the contract balance.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn withdraw_shop_revenue(&mut self, currency: ShopCurrency) -> Result<Balance>;
+}
+
+#[openbrush::wrapper]
+pub type QuestsRef = dyn Quests;
+
+/// Trait definitions for Quests ink! messages
+#[openbrush::trait_definition]
+pub trait Quests {
+    /// Register a new quest. Return its id.
+    /// Fails with `ItemNotFound` for unknown reward items and `BadConfig` if health must be kept
+    /// for no day or for more days than the game tracks, or if stakes don't have to be locked.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn add_quest(&mut self, quest: Quest) -> Result<QuestId>;
+
+    /// Replace a quest. Accounts which already claimed it can't claim it again.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn update_quest(&mut self, quest_id: QuestId, quest: Quest) -> Result<()>;
+
+    /// Remove a quest.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn remove_quest(&mut self, quest_id: QuestId) -> Result<()>;
+
+    /// Used to retrieve a quest.
+    #[ink(message)]
+    fn get_quest(&self, quest_id: QuestId) -> Option<Quest>;
+
+    /// Used to retrieve the registered quests along with their ids.
+    /// Only the ids from `from` are looked at, at most `limit` of them. Ids start at 1.
+    #[ink(message)]
+    fn get_quests(&self, from: QuestId, limit: u32) -> Vec<(QuestId, Quest)>;
+
+    /// Used to retrieve the progress of the account on the quest and the target to reach.
+    /// Health is counted for the pet, on the days its status was updated.
+    /// Fails with `QuestNotFound` for unknown quests.
+    #[ink(message)]
+    fn get_quest_progress(
+        &self,
+        quest_id: QuestId,
+        account_id: AccountId,
+        token_id: Id,
+    ) -> Result<(u64, u64)>;
+
+    /// Claim the rewards of a completed quest for the caller, once per account.
+    /// Quests reached by a pet, such as keeping its health, are claimed once per pet instead,
+    /// even after it changed hands.
+    /// The pet is the one health is counted for and receives the achievement badge.
+    /// Fails with `QuestNotCompleted` until the target is reached and `QuestAlreadyClaimed` after.
+    /// The caller must own the pet.
+    #[ink(message)]
+    fn claim_quest(&mut self, quest_id: QuestId, token_id: Id) -> Result<()>;
+
+    /// Used to retrieve the time the quest was claimed, if it was.
+    /// Quests reached by a pet are looked up for `token_id`, the others for `account_id`.
+    #[ink(message)]
+    fn get_quest_claimed_at(
+        &self,
+        quest_id: QuestId,
+        account_id: AccountId,
+        token_id: Id,
+    ) -> Option<u64>;
+}
+
+#[openbrush::wrapper]
+pub type RateLimitRef = dyn RateLimit;
+
+/// Trait definitions for the messages configuring the rate limits of player actions.
+/// Calls over a limit fail with `RateLimited`, or `BlockCapReached` once the block is full.
+///
+/// Every player message changing state counts towards one action:
+/// `feed` (`eat_an_apple`, `use_item`), `buy` (`buy_item`, `buy_listing`),
+/// `daily_bonus`, `checkpoint`, `randomness` (`commit_randomness`, `reveal_randomness`),
+/// `play`, `breed`, `consent` (`withdraw_consent`),
+/// `stake` (`stake`, `unstake`, `fund_staking_treasury`), `claim` (`claim_staking_rewards`),
+/// `quest` (`claim_quest`) and `batch` (`feed_many`, `claim_all`, `execute_actions`).
+/// The actions of a batch also count on their own. `buy_an_apple` counts through `buy_item`.
+/// Messages restricted to a role aren't limited
+#[openbrush::trait_definition]
+pub trait RateLimit {
+    /// Set the budget of each account for the action. `None` lifts the limit.
+    /// Fails with `BadConfig` for an empty window.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_action_budget(
+        &mut self,
+        action: PreludeString,
+        budget: Option<ActionBudget>,
+    ) -> Result<()>;
+
+    /// Used to retrieve the budget of each account for the action.
+    #[ink(message)]
+    fn get_action_budget(&self, action: PreludeString) -> Option<ActionBudget>;
+
+    /// Set the most rate limited calls all accounts can make in one block. `None` is unlimited.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn set_block_cap(&mut self, cap: Option<u32>) -> Result<()>;
+
+    /// Used to retrieve the most rate limited calls all accounts can make in one block.
+    #[ink(message)]
+    fn get_block_cap(&self) -> Option<u32>;
+
+    /// Used to retrieve the calls the account has left for the action in its current window.
+    /// `None` if the action has no budget.
+    #[ink(message)]
+    fn get_remaining_calls(&self, action: PreludeString, account_id: AccountId) -> Option<u32>;
+}
+
+#[openbrush::wrapper]
+pub type SeasonsRef = dyn Seasons;
+
+/// Trait definitions for the messages running the seasons of the game economy.
+/// The in-game currency, the items and the stakes of the accounts are kept for each season.
+/// Balances held in a PSP22 currency are not part of the seasons
+#[openbrush::trait_definition]
+pub trait Seasons {
+    /// Used to retrieve the current season.
+    #[ink(message)]
+    fn get_season(&self) -> u32;
+
+    /// End the current season and start a new one. Accounts keep the share of their currency
+    /// and items set by `carry_over`, and their stakes if it keeps them. `None` resets everything.
+    /// The currency of the reset stakes goes to the staking treasury. Stakes are always kept
+    /// while a PSP22 currency is set.
+    /// Fails with `BadConfig` for shares above 100%.
+    /// Requires the `ADMIN` role.
+    #[ink(message)]
+    fn start_new_season(&mut self, carry_over: Option<CarryOver>) -> Result<()>;
+
+    /// Used to retrieve the share of the balances kept when `season` ended.
+    /// `None` until it ends.
+    #[ink(message)]
+    fn get_carry_over(&self, season: u32) -> Option<CarryOver>;
+
+    /// Used to retrieve the in-game currency the account owned in `season`.
+    /// For past seasons, the balance it ended with.
+    #[ink(message)]
+    fn get_season_money(&self, season: u32, account_id: AccountId) -> u64;
+
+    /// Used to retrieve the number of `item_id` the account owned in `season`.
+    /// For past seasons, the balance it ended with.
+    #[ink(message)]
+    fn get_season_items(&self, season: u32, account_id: AccountId, item_id: ItemId) -> u32;
+
+    /// Used to retrieve the currency the account staked in `season`, over all its stakes.
+    /// For past seasons, the stakes it ended with.
+    #[ink(message)]
+    fn get_season_staked_money(&self, season: u32, account_id: AccountId) -> u64;
+}
+
+#[openbrush::wrapper]
+pub type BatchRef = dyn Batch;
+
+/// Trait definitions for the messages running several player actions at once.
+/// A batch fails as a whole with the error of the first failing action,
+/// in which case none of its actions is applied
+#[openbrush::trait_definition]
+pub trait Batch {
+    /// Use one item of each pair on its pet, in order.
+    /// Fails with `TooManyActions` above the batch size limit.
+    /// The caller must own the pets.
+    #[ink(message)]
+    fn feed_many(&mut self, feedings: Vec<(Id, ItemId)>) -> Result<()>;
+
+    /// Claim the daily bonus if its cooldown is over and the rewards of every stake of the caller.
+    /// Return the in-game currency paid in total.
+    #[ink(message)]
+    fn claim_all(&mut self) -> Result<u64>;
+
+    /// Run the actions of the caller in order. Return the result of each action.
+    /// Fails with `TooManyActions` above the batch size limit.
+    #[ink(message)]
+    fn execute_actions(&mut self, actions: Vec<GameAction>) -> Result<Vec<ActionResult>>;
+}
+
+/// Trait definitions for PetGame ink events
+#[openbrush::trait_definition]
+pub trait PetGameEvents {
+    /// Used to notify listeners that the stored status of a pet has changed.
+    /// # Arguments:
+    /// * tokenId ID of the pet
+    /// * status New status of the pet
+    fn _emit_status_changed_event(&self, token_id: &Id, status: &Status);
+
+    /// Used to notify listeners which outcome a feeding rolled.
+    /// # Arguments:
+    /// * tokenId ID of the pet
+    /// * index Index of the outcome in the outcome table
+    /// * effect Effect of the outcome on the pet
+    fn _emit_feed_outcome_event(&self, token_id: &Id, index: u32, effect: &StatusEffect);
+
+    /// Used to notify listeners that an item was used on a pet.
+    /// # Arguments:
+    /// * tokenId ID of the pet
+    /// * accountId Account the item was taken from
+    /// * itemId ID of the item
+    fn _emit_item_used_event(&self, token_id: &Id, account_id: &AccountId, item_id: ItemId);
+
+    /// Used to notify listeners that a pet moved to another stage of life.
+    /// # Arguments:
+    /// * tokenId ID of the pet
+    /// * stage New stage of life of the pet
+    fn _emit_lifecycle_changed_event(&self, token_id: &Id, stage: LifecycleStage);
+
+    /// Used to notify listeners that two pets played together.
+    /// # Arguments:
+    /// * tokenId ID of the pet whose owner completed the play date
+    /// * otherTokenId ID of the other pet
+    fn _emit_pets_played_event(&self, token_id: &Id, other_token_id: &Id);
+
+    /// Used to notify listeners that two pets had a child.
+    /// # Arguments:
+    /// * parentId ID of the parent the child is nested in
+    /// * otherParentId ID of the other parent
+    /// * childId ID of the child
+    fn _emit_pet_bred_event(&self, parent_id: &Id, other_parent_id: &Id, child_id: &Id);
+
+    /// Used to notify listeners that the daily bonus was claimed.
+    /// # Arguments:
+    /// * accountId Account that claimed the bonus
+    /// * streak Number of consecutive claims including this one
+    /// * reward Reward paid for the claim
+    fn _emit_bonus_claimed_event(&self, account_id: &AccountId, streak: u32, reward: &BonusReward);
+
+    /// Used to notify listeners that items were bought.
+    /// # Arguments:
+    /// * accountId Account that bought the items
+    /// * itemId ID of the item
+    /// * qty Number of items bought
+    /// * price Currency paid for all of them
+    fn _emit_item_bought_event(
+        &self,
+        account_id: &AccountId,
+        item_id: ItemId,
+        qty: u32,
+        price: u64,
+    );
+
+    /// Used to notify listeners that a shop listing was bought.
+    /// # Arguments:
+    /// * accountId Account that bought the items
+    /// * listingId ID of the listing
+    /// * qty Number of items bought
+    /// * price Amount paid for all of them in the currency of the listing
+    fn _emit_listing_bought_event(
+        &self,
+        account_id: &AccountId,
+        listing_id: ListingId,
+        qty: u32,
+        price: Balance,
+    );
+
+    /// Used to notify listeners that currency was staked.
+    /// # Arguments:
+    /// * accountId Account that staked
+    /// * lockId Lock option of the stake. `None` is the stake without lock
+    /// * amount Currency added to the stake
+    fn _emit_staked_event(&self, account_id: &AccountId, lock_id: Option<u32>, amount: u64);
+
+    /// Used to notify listeners that currency was withdrawn from a stake.
+    /// # Arguments:
+    /// * accountId Account that withdrew
+    /// * lockId Lock option of the stake. `None` is the stake without lock
+    /// * amount Currency withdrawn from the stake
+    /// * rewards Rewards paid along with it
+    fn _emit_unstaked_event(
+        &self,
+        account_id: &AccountId,
+        lock_id: Option<u32>,
+        amount: u64,
+        rewards: u64,
+    );
+
+    /// Used to notify listeners that staking rewards were claimed.
+    /// # Arguments:
+    /// * accountId Account that claimed
+    /// * lockId Lock option of the stake. `None` is the stake without lock
+    /// * rewards Rewards paid
+    fn _emit_staking_rewards_claimed_event(
+        &self,
+        account_id: &AccountId,
+        lock_id: Option<u32>,
+        rewards: u64,
+    );
+
+    /// Used to notify listeners that a quest was claimed.
+    /// # Arguments:
+    /// * accountId Account that claimed the quest
+    /// * questId ID of the quest
+    /// * tokenId ID of the pet the quest was claimed with
+    fn _emit_quest_claimed_event(&self, account_id: &AccountId, quest_id: QuestId, token_id: &Id);
+}
@@ -0,0 +1,2558 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+#[openbrush::contract]
+pub mod rmrk_contract_petgame {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        contracts::{
+            access_control::*,
+            psp34::extensions::{
+                enumerable::*,
+                metadata::*,
+            },
+            reentrancy_guard::*,
+        },
+        traits::Storage,
+    };
+
+    use rmrk_common::{
+        roles::{
+            CONTRIBUTOR,
+            GAME_MASTER,
+        },
+        types::Status,
+    };
+    use rmrk_minting::MintingData;
+    use rmrk_multiasset::{
+        traits::*,
+        MultiAssetData,
+    };
+    use rmrk_nesting::NestingData;
+    use rmrk_petgame::{
+        traits::*,
+        PetGameData,
+        QuestData,
+        RateLimitData,
+        ShopData,
+        StakingData,
+    };
+
+    /// Event emitted when the stored status of a pet changes.
+    #[ink(event)]
+    pub struct StatusChanged {
+        #[ink(topic)]
+        token: Id,
+        status: Status,
+    }
+
+    // Rmrk contract storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Rmrk {
+        #[storage_field]
+        psp34: psp34::Data<enumerable::Balances>,
+        #[storage_field]
+        guard: reentrancy_guard::Data,
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+        #[storage_field]
+        multiasset: MultiAssetData,
+        #[storage_field]
+        minting: MintingData,
+        #[storage_field]
+        nesting: NestingData,
+        #[storage_field]
+        petgame: PetGameData,
+        #[storage_field]
+        staking: StakingData,
+        #[storage_field]
+        shop: ShopData,
+        #[storage_field]
+        quests: QuestData,
+        #[storage_field]
+        rate_limit: RateLimitData,
+    }
+
+    impl PSP34 for Rmrk {}
+
+    impl AccessControl for Rmrk {}
+
+    impl PSP34Metadata for Rmrk {}
+
+    impl PSP34Enumerable for Rmrk {}
+
+    impl MultiAsset for Rmrk {}
+
+    impl PetGame for Rmrk {}
+
+    impl Staking for Rmrk {}
+
+    impl Batch for Rmrk {}
+
+    impl Shop for Rmrk {}
+
+    impl Quests for Rmrk {}
+
+    impl RateLimit for Rmrk {}
+
+    impl Seasons for Rmrk {}
+
+    impl Interactions for Rmrk {}
+
+    impl PetGameEvents for Rmrk {
+        /// Emit StatusChanged event
+        fn _emit_status_changed_event(&self, token_id: &Id, status: &Status) {
+            self.env().emit_event(StatusChanged {
+                token: token_id.clone(),
+                status: status.clone(),
+            });
+        }
+    }
+
+    impl Rmrk {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Rmrk::default();
+            instance._init_with_admin(instance.env().caller());
+            instance._setup_role(CONTRIBUTOR, instance.env().caller());
+            instance._setup_role(GAME_MASTER, instance.env().caller());
+            instance
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{
+            Rmrk,
+            StatusChanged,
+        };
+
+        use ink::env::test;
+
+        use ink::prelude::vec::Vec;
+
+        use openbrush::{
+            contracts::{
+                access_control::*,
+                psp34::extensions::{
+                    enumerable::*,
+                    metadata::PSP34Metadata,
+                },
+            },
+            traits::{
+                AccountId,
+                Hash,
+                String,
+            },
+        };
+
+        use rmrk_common::{
+            errors::*,
+            roles::GAME_MASTER,
+            types::*,
+        };
+
+        use rmrk_multiasset::traits::MultiAsset;
+
+        use rmrk_petgame::{
+            batch::MAX_BATCH_SIZE,
+            default_apple,
+            default_feed_outcomes,
+            randomness::{
+                keccak,
+                RandomnessSource,
+                VRF_FUNCTION_ID,
+            },
+            staking::YEAR,
+            traits::{
+                Batch,
+                Interactions,
+                PetGame,
+                Quests,
+                RateLimit,
+                Seasons,
+                Shop,
+                Staking,
+            },
+            APPLE_ITEM_ID,
+            DAY,
+            DEATH_STATUS,
+            DEFAULT_STATUS_HISTORY_CAPACITY,
+            FULL_STATUS,
+            HEALTH_DAYS_KEPT,
+            MAX_STATUS_HISTORY_CAPACITY,
+        };
+
+        const TOKEN_ID: Id = Id::U64(1);
+
+        fn init() -> Rmrk {
+            Rmrk::new()
+        }
+
+        fn default_accounts() -> test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            test::default_accounts::<ink::env::DefaultEnvironment>()
+        }
+
+        fn set_sender(sender: AccountId) {
+            test::set_caller::<ink::env::DefaultEnvironment>(sender);
+        }
+
+        fn set_timestamp(timestamp: u64) {
+            test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        type Event = <Rmrk as ink::reflect::ContractEventBase>::Type;
+
+        fn recorded_events() -> Vec<Event> {
+            test::recorded_events()
+                .map(|event| <Event as scale::Decode>::decode(&mut &event.data[..]).unwrap())
+                .collect()
+        }
+
+        /// VRF chain extension always returning the same bytes
+        struct MockVrf(u8);
+
+        impl test::ChainExtension for MockVrf {
+            fn func_id(&self) -> u32 {
+                VRF_FUNCTION_ID
+            }
+
+            fn call(&mut self, _input: &[u8], output: &mut Vec<u8>) -> u32 {
+                output.extend_from_slice(&[self.0; 32]);
+                0
+            }
+        }
+
+        #[ink::test]
+        fn game_master_setters_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+
+            assert!(rmrk.set_status(TOKEN_ID, 1, 2, 3).is_ok());
+            assert_eq!(rmrk.get_status(TOKEN_ID).unwrap().health, 2);
+            assert!(rmrk.set_your_money(accounts.bob, 100).is_ok());
+            assert!(rmrk.plus_your_money(accounts.bob, 50).is_ok());
+            assert!(rmrk.minus_your_money(accounts.bob, 30).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.bob), 120);
+            assert!(rmrk.set_your_apple(accounts.bob, 3).is_ok());
+            assert!(rmrk.minus_your_apple(accounts.bob).is_ok());
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 2);
+            assert!(rmrk.set_your_apple(accounts.bob, 0).is_ok());
+            assert_eq!(
+                rmrk.minus_your_apple(accounts.bob),
+                Err(RmrkError::NotEnoughApple.into())
+            );
+            assert!(rmrk.set_last_eaten(TOKEN_ID, 42).is_ok());
+            assert_eq!(rmrk.get_last_eaten(TOKEN_ID), 42);
+            assert!(rmrk.set_last_bonus(accounts.bob, 42).is_ok());
+            assert_eq!(rmrk.get_last_bonus(accounts.bob), 42);
+        }
+
+        #[ink::test]
+        fn unauthorized_setters_fail() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_status(TOKEN_ID, 0, 999, 999),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.set_full_status(TOKEN_ID),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.set_death_status(TOKEN_ID),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.change_some_status(TOKEN_ID, 999),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.set_your_money(accounts.bob, 1_000_000),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.plus_your_money(accounts.bob, 1_000_000),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.set_your_apple(accounts.bob, 100),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.set_last_eaten(TOKEN_ID, 0),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.set_last_bonus(accounts.bob, 0),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.set_default(accounts.bob),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(rmrk.get_status(TOKEN_ID), None);
+            assert_eq!(rmrk.get_your_money(accounts.bob), 0);
+
+            // granted game master can write
+            set_sender(accounts.alice);
+            assert!(rmrk.grant_role(GAME_MASTER, accounts.bob).is_ok());
+            set_sender(accounts.bob);
+            assert!(rmrk.set_your_money(accounts.bob, 10).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.bob), 10);
+        }
+
+        #[ink::test]
+        fn eat_an_apple_requires_token_owner() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 1).is_ok());
+            assert!(rmrk.set_your_apple(accounts.charlie, 1).is_ok());
+            set_timestamp(61_000);
+
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.eat_an_apple(TOKEN_ID, accounts.charlie),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.eat_an_apple(TOKEN_ID, accounts.charlie),
+                Err(RmrkError::InvalidAccountId.into())
+            );
+            assert!(rmrk.eat_an_apple(TOKEN_ID, accounts.bob).is_ok());
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 0);
+            assert_eq!(rmrk.get_your_apple(accounts.charlie), 1);
+        }
+
+        #[ink::test]
+        fn player_actions_require_account_owner() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk.set_your_money(accounts.bob, 100).is_ok());
+            set_timestamp(61_000);
+
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.buy_an_apple(accounts.bob),
+                Err(RmrkError::InvalidAccountId.into())
+            );
+            assert_eq!(
+                rmrk.daily_bonus(accounts.bob),
+                Err(RmrkError::InvalidAccountId.into())
+            );
+            assert_eq!(rmrk.get_your_money(accounts.bob), 100);
+
+            set_sender(accounts.bob);
+            assert!(rmrk.buy_an_apple(accounts.bob).is_ok());
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 1);
+            assert_eq!(rmrk.get_your_money(accounts.bob), 80);
+        }
+
+        #[ink::test]
+        fn decay_config_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_decay_config(), DecayConfig::default());
+
+            let config = DecayConfig {
+                time_unit: 1_000,
+                grace_period: 10_000,
+                hungry_rate: 2,
+                health_rate: 3,
+                happy_rate: 1,
+                hungry_cap: 50,
+                health_floor: 10,
+                happy_floor: 0,
+            };
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_decay_config(config.clone()),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_decay_config(DecayConfig {
+                    time_unit: 0,
+                    ..config.clone()
+                }),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert!(rmrk.set_decay_config(config.clone()).is_ok());
+            assert_eq!(rmrk.get_decay_config(), config);
+
+            set_timestamp(1_000);
+            assert!(rmrk.set_status(TOKEN_ID, 10, 100, 20).is_ok());
+            assert!(rmrk.set_last_eaten(TOKEN_ID, 1_000).is_ok());
+
+            // nothing decays during the grace period
+            set_timestamp(11_000);
+            assert_eq!(
+                rmrk.get_current_status(TOKEN_ID),
+                Some(Status {
+                    hungry: 10,
+                    health: 100,
+                    happy: 20,
+                })
+            );
+
+            // 5 time units after the grace period
+            set_timestamp(16_000);
+            assert_eq!(
+                rmrk.get_current_status(TOKEN_ID),
+                Some(Status {
+                    hungry: 20,
+                    health: 85,
+                    happy: 15,
+                })
+            );
+            assert_eq!(rmrk.get_total_status(TOKEN_ID), 80);
+            assert_eq!(rmrk.get_condition(TOKEN_ID), Condition::Bad);
+
+            // stats stop at their cap and floors
+            set_timestamp(1_000_000);
+            assert_eq!(
+                rmrk.get_current_status(TOKEN_ID),
+                Some(Status {
+                    hungry: 50,
+                    health: 10,
+                    happy: 0,
+                })
+            );
+        }
+
+        #[ink::test]
+        fn checkpoint_persists_decay() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert_eq!(
+                rmrk.checkpoint(TOKEN_ID),
+                Err(PSP34Error::TokenNotExists.into())
+            );
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+
+            // absolute writes restart the decay
+            set_timestamp(60_000);
+            assert!(rmrk.set_status(TOKEN_ID, 0, 100, 100).is_ok());
+            assert_eq!(rmrk.get_last_checkpoint(TOKEN_ID), 60_000);
+
+            // one and a half time units later only the whole step is persisted
+            set_timestamp(150_000);
+            let decayed = Status {
+                hungry: 5,
+                health: 95,
+                happy: 95,
+            };
+            assert_eq!(rmrk.get_current_status(TOKEN_ID), Some(decayed.clone()));
+            assert_eq!(rmrk.get_status(TOKEN_ID), Some(FULL_STATUS));
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_status(TOKEN_ID), Some(decayed.clone()));
+            assert_eq!(rmrk.get_last_checkpoint(TOKEN_ID), 120_000);
+
+            // the checkpoint doesn't change the result of later reads
+            set_timestamp(180_000);
+            assert_eq!(
+                rmrk.get_current_status(TOKEN_ID),
+                Some(Status {
+                    hungry: 10,
+                    health: 90,
+                    happy: 90,
+                })
+            );
+
+            // relative changes apply to the decayed status, up to the maximum stat
+            assert!(rmrk.change_some_status(TOKEN_ID, 5).is_ok());
+            assert_eq!(
+                rmrk.get_status(TOKEN_ID),
+                Some(Status {
+                    hungry: 5,
+                    health: 95,
+                    happy: 95,
+                })
+            );
+            assert!(rmrk.change_some_status(TOKEN_ID, 20).is_ok());
+            assert_eq!(rmrk.get_status(TOKEN_ID), Some(FULL_STATUS));
+            assert_eq!(rmrk.get_last_checkpoint(TOKEN_ID), 180_000);
+
+            set_timestamp(200_000);
+            assert!(rmrk.set_full_status(TOKEN_ID).is_ok());
+            set_timestamp(259_000);
+            assert_eq!(rmrk.get_current_status(TOKEN_ID), Some(FULL_STATUS));
+        }
+
+        #[ink::test]
+        fn eat_an_apple_uses_vrf() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 1).is_ok());
+            assert_eq!(rmrk.get_randomness_kind(), RandomnessKind::Hash);
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_randomness_kind(RandomnessKind::ChainExtension),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert!(rmrk
+                .set_randomness_kind(RandomnessKind::ChainExtension)
+                .is_ok());
+            assert_eq!(rmrk.get_randomness_kind(), RandomnessKind::ChainExtension);
+
+            // 0x01010101 rolls 47 out of 101, a full status with the default table
+            test::register_chain_extension(MockVrf(1));
+            set_timestamp(61_000);
+            set_sender(accounts.bob);
+            assert!(rmrk.eat_an_apple(TOKEN_ID, accounts.bob).is_ok());
+            assert_eq!(rmrk.get_status(TOKEN_ID), Some(FULL_STATUS));
+        }
+
+        #[ink::test]
+        fn eat_an_apple_uses_commit_reveal() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 2).is_ok());
+            assert!(rmrk
+                .set_randomness_kind(RandomnessKind::CommitReveal { vrf: false })
+                .is_ok());
+            set_timestamp(61_000);
+
+            // pets are only fed along with the reveal
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.eat_an_apple(TOKEN_ID, accounts.bob),
+                Err(RmrkError::RandomnessNotRevealed.into())
+            );
+
+            let secret = [7u8; 16].to_vec();
+            let commitment = Hash::from(keccak(&secret));
+            assert_eq!(
+                rmrk.reveal_randomness(TOKEN_ID, secret.clone(), APPLE_ITEM_ID),
+                Err(RmrkError::CommitmentNotFound.into())
+            );
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.commit_randomness(TOKEN_ID, commitment),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+            set_sender(accounts.bob);
+            assert!(rmrk.commit_randomness(TOKEN_ID, commitment).is_ok());
+            assert_eq!(
+                rmrk.commit_randomness(TOKEN_ID, commitment),
+                Err(RmrkError::CommitmentPending.into())
+            );
+            assert_eq!(
+                rmrk.reveal_randomness(TOKEN_ID, secret.clone(), APPLE_ITEM_ID),
+                Err(RmrkError::RevealTooEarly.into())
+            );
+
+            // the runtime VRF is only needed when it is mixed in
+            test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(
+                rmrk.reveal_randomness(TOKEN_ID, [8u8; 16].to_vec(), APPLE_ITEM_ID),
+                Err(RmrkError::InvalidReveal.into())
+            );
+            assert!(rmrk
+                .reveal_randomness(TOKEN_ID, secret.clone(), APPLE_ITEM_ID)
+                .is_ok());
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 1);
+            // the seed is gone before anyone can read it
+            assert_eq!(rmrk.petgame.revealed_seeds.get(&TOKEN_ID), None);
+
+            // each commitment is revealed once
+            set_timestamp(122_000);
+            assert_eq!(
+                rmrk.reveal_randomness(TOKEN_ID, secret.clone(), APPLE_ITEM_ID),
+                Err(RmrkError::CommitmentNotFound.into())
+            );
+            assert!(rmrk.commit_randomness(TOKEN_ID, commitment).is_ok());
+        }
+
+        #[ink::test]
+        fn commit_reveal_mixes_in_entropy_drawn_at_use() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            let draw = |rmrk: &mut Rmrk, timestamp: u64, vrf: bool| {
+                set_timestamp(timestamp);
+                rmrk.petgame.salt = 0;
+                rmrk.petgame.revealed_seeds.insert(&TOKEN_ID, &[7u8; 32]);
+                rmrk._commit_reveal_randomness(&TOKEN_ID, vrf)
+            };
+
+            // same seed, different block of use
+            let first = draw(&mut rmrk, 1_000, false).unwrap();
+            assert_ne!(first, draw(&mut rmrk, 2_000, false).unwrap());
+            assert_eq!(first, draw(&mut rmrk, 1_000, false).unwrap());
+
+            // the runtime VRF is optional
+            assert_eq!(
+                draw(&mut rmrk, 1_000, true),
+                Err(RmrkError::RandomnessUnavailable.into())
+            );
+            test::register_chain_extension(MockVrf(1));
+            let with_vrf = draw(&mut rmrk, 1_000, true).unwrap();
+            assert_ne!(with_vrf, first);
+            test::register_chain_extension(MockVrf(2));
+            assert_ne!(with_vrf, draw(&mut rmrk, 1_000, true).unwrap());
+        }
+
+        #[ink::test]
+        fn default_feeding_is_not_lethal() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(default_feed_outcomes().iter().all(|outcome| {
+                match &outcome.effect {
+                    StatusEffect::Change(delta) => delta.health >= 0,
+                    StatusEffect::Set(status) => status.health > 0,
+                }
+            }));
+
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk.set_full_status(TOKEN_ID).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 20).is_ok());
+            set_sender(accounts.bob);
+            for feeding in 1..=20 {
+                set_timestamp(feeding * 61_000);
+                assert!(rmrk.eat_an_apple(TOKEN_ID, accounts.bob).is_ok());
+                assert_ne!(rmrk.get_lifecycle_stage(TOKEN_ID), LifecycleStage::Dead);
+            }
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn feed_outcomes_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 1).is_ok());
+            assert_eq!(rmrk.get_feed_outcomes(), default_feed_outcomes());
+            assert_eq!(rmrk.get_feed_odds(), [2475, 2475, 2475, 2574]);
+
+            let lucky = FeedOutcome {
+                weight: 3,
+                effect: StatusEffect::Set(Status {
+                    hungry: 1,
+                    health: 2,
+                    happy: 3,
+                }),
+            };
+            let unlucky = FeedOutcome {
+                weight: 0,
+                effect: StatusEffect::Set(DEATH_STATUS),
+            };
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_feed_outcomes([lucky.clone()].to_vec()),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_feed_outcomes(Vec::new()),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert_eq!(
+                rmrk.set_feed_outcomes([unlucky.clone()].to_vec()),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert_eq!(
+                rmrk.set_feed_outcomes(
+                    [
+                        FeedOutcome {
+                            weight: u32::MAX,
+                            ..lucky.clone()
+                        },
+                        lucky.clone(),
+                    ]
+                    .to_vec()
+                ),
+                Err(RmrkError::BadConfig.into())
+            );
+
+            // outcomes without weight are never rolled
+            let outcomes = [unlucky, lucky].to_vec();
+            assert!(rmrk.set_feed_outcomes(outcomes.clone()).is_ok());
+            assert_eq!(rmrk.get_feed_outcomes(), outcomes);
+            assert_eq!(rmrk.get_feed_odds(), [0, 10_000]);
+
+            set_timestamp(61_000);
+            set_sender(accounts.bob);
+            assert!(rmrk.eat_an_apple(TOKEN_ID, accounts.bob).is_ok());
+            assert_eq!(
+                rmrk.get_status(TOKEN_ID),
+                Some(Status {
+                    hungry: 1,
+                    health: 2,
+                    happy: 3,
+                })
+            );
+        }
+
+        #[ink::test]
+        fn items_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_item(APPLE_ITEM_ID), Some(default_apple()));
+            assert_eq!(rmrk.get_item(1), None);
+
+            let treat = Item {
+                name: "Treat".into(),
+                price: 30,
+                effect: ItemEffect::Apply(StatusEffect::Change(StatusDelta {
+                    hungry: -10,
+                    health: 0,
+                    happy: 20,
+                })),
+                cooldown: 10_000,
+            };
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.add_item(treat.clone()),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(rmrk.add_item(treat.clone()), Ok(1));
+            assert_eq!(rmrk.get_last_item_id(), 1);
+            assert_eq!(rmrk.get_item(1), Some(treat.clone()));
+            assert_eq!(
+                rmrk.update_item(2, treat.clone()),
+                Err(RmrkError::ItemNotFound.into())
+            );
+            assert!(rmrk.set_your_money(accounts.bob, 100).is_ok());
+            set_timestamp(100_000);
+            assert!(rmrk.set_status(TOKEN_ID, 50, 10, 10).is_ok());
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.use_item(TOKEN_ID, 1),
+                Err(RmrkError::NotEnoughItems.into())
+            );
+            assert_eq!(rmrk.buy_item(2, 1), Err(RmrkError::ItemNotFound.into()));
+            assert_eq!(rmrk.buy_item(1, 4), Err(RmrkError::NotEnoughMoney.into()));
+            assert!(rmrk.buy_item(1, 3).is_ok());
+            assert_eq!(rmrk.get_item_balance(accounts.bob, 1), 3);
+            assert_eq!(rmrk.get_your_money(accounts.bob), 10);
+
+            assert!(rmrk.use_item(TOKEN_ID, 1).is_ok());
+            assert_eq!(
+                rmrk.get_status(TOKEN_ID),
+                Some(Status {
+                    hungry: 40,
+                    health: 10,
+                    happy: 30,
+                })
+            );
+            assert_eq!(rmrk.get_item_balance(accounts.bob, 1), 2);
+            assert_eq!(rmrk.get_item_last_used(TOKEN_ID, 1), 100_000);
+            assert_eq!(rmrk.get_last_eaten(TOKEN_ID), 100_000);
+
+            // the cooldown applies per item
+            assert_eq!(
+                rmrk.use_item(TOKEN_ID, 1),
+                Err(RmrkError::TimeHasNotPassed(10_000).into())
+            );
+            assert_eq!(
+                rmrk.use_item(TOKEN_ID, APPLE_ITEM_ID),
+                Err(RmrkError::NotEnoughApple.into())
+            );
+            set_timestamp(110_001);
+            assert!(rmrk.use_item(TOKEN_ID, 1).is_ok());
+            assert_eq!(
+                rmrk.get_status(TOKEN_ID),
+                Some(Status {
+                    hungry: 30,
+                    health: 10,
+                    happy: 50,
+                })
+            );
+
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.use_item(TOKEN_ID, 1),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+        }
+
+        #[ink::test]
+        fn cooldowns_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 3).is_ok());
+            assert_eq!(rmrk.get_cooldown(Cooldown::Feed), 60_000);
+            assert_eq!(rmrk.get_cooldown(Cooldown::DailyBonus), 60_000);
+            assert_eq!(rmrk.get_cooldown(Cooldown::Item(APPLE_ITEM_ID)), 0);
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_cooldown(Cooldown::Feed, 30_000),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_cooldown(Cooldown::Item(APPLE_ITEM_ID), 30_000),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert!(rmrk.set_cooldown(Cooldown::Feed, 30_000).is_ok());
+            assert_eq!(rmrk.get_cooldown(Cooldown::Feed), 30_000);
+
+            // feeding is tracked per pet
+            let pet = CooldownSubject::Token(TOKEN_ID);
+            set_timestamp(1_000);
+            set_sender(accounts.bob);
+            assert_eq!(rmrk.get_cooldown_remaining(Cooldown::Feed, pet.clone()), 0);
+            assert!(rmrk.eat_an_apple(TOKEN_ID, accounts.bob).is_ok());
+            assert_eq!(rmrk.get_last_used(Cooldown::Feed, pet.clone()), 1_000);
+            set_timestamp(21_000);
+            assert_eq!(
+                rmrk.get_cooldown_remaining(Cooldown::Feed, pet.clone()),
+                10_000
+            );
+            assert_eq!(
+                rmrk.eat_an_apple(TOKEN_ID, accounts.bob),
+                Err(RmrkError::TimeHasNotPassed(10_000).into())
+            );
+            set_timestamp(31_000);
+            assert!(rmrk.eat_an_apple(TOKEN_ID, accounts.bob).is_ok());
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 1);
+
+            // the daily bonus is tracked per account
+            let bob = CooldownSubject::Account(accounts.bob);
+            assert!(rmrk.daily_bonus(accounts.bob).is_ok());
+            assert_eq!(rmrk.get_last_bonus(accounts.bob), 31_000);
+            set_timestamp(81_000);
+            assert_eq!(
+                rmrk.daily_bonus(accounts.bob),
+                Err(RmrkError::TimeHasNotPassed(10_000).into())
+            );
+            assert_eq!(
+                rmrk.get_cooldown_remaining(Cooldown::DailyBonus, bob),
+                10_000
+            );
+            set_sender(accounts.charlie);
+            assert!(rmrk.daily_bonus(accounts.charlie).is_ok());
+        }
+
+        #[ink::test]
+        fn bonus_streaks_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert_eq!(rmrk.get_bonus_config(), BonusConfig::default());
+            let reward = |money| BonusReward { money, item: None };
+            let weekly = BonusReward {
+                money: 500,
+                item: Some((APPLE_ITEM_ID, 2)),
+            };
+            let config = BonusConfig {
+                rewards: [reward(100), reward(200), weekly.clone()].to_vec(),
+                claim_window: 1_000,
+            };
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_bonus_config(config.clone()),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_bonus_config(BonusConfig {
+                    rewards: Vec::new(),
+                    claim_window: 1_000,
+                }),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert!(rmrk.set_bonus_config(config.clone()).is_ok());
+            assert!(rmrk.set_cooldown(Cooldown::DailyBonus, 10_000).is_ok());
+
+            set_sender(accounts.bob);
+            set_timestamp(1_000);
+            assert_eq!(rmrk.get_bonus_streak(accounts.bob), 0);
+            assert_eq!(rmrk.get_next_bonus(accounts.bob), reward(100));
+            assert!(rmrk.daily_bonus(accounts.bob).is_ok());
+            assert_eq!(rmrk.get_bonus_streak(accounts.bob), 1);
+            assert_eq!(rmrk.get_next_bonus(accounts.bob), reward(200));
+
+            set_timestamp(11_000);
+            assert!(rmrk.daily_bonus(accounts.bob).is_ok());
+            set_timestamp(22_000);
+            assert!(rmrk.daily_bonus(accounts.bob).is_ok());
+            assert_eq!(rmrk.get_bonus_streak(accounts.bob), 3);
+            assert_eq!(rmrk.get_your_money(accounts.bob), 800);
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 2);
+
+            // the last reward repeats for longer streaks
+            assert_eq!(rmrk.get_next_bonus(accounts.bob), weekly);
+
+            // missing the claim window restarts the streak
+            set_timestamp(33_001);
+            assert_eq!(rmrk.get_bonus_streak(accounts.bob), 0);
+            assert_eq!(rmrk.get_next_bonus(accounts.bob), reward(100));
+            assert!(rmrk.daily_bonus(accounts.bob).is_ok());
+            assert_eq!(rmrk.get_bonus_streak(accounts.bob), 1);
+            assert_eq!(rmrk.get_your_money(accounts.bob), 900);
+        }
+
+        #[ink::test]
+        fn lifecycle_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 1).is_ok());
+            assert_eq!(rmrk.get_lifecycle_stage(TOKEN_ID), LifecycleStage::Egg);
+            assert_eq!(rmrk.get_born_at(TOKEN_ID), 0);
+
+            let config = LifecycleConfig {
+                hatch_age: 1_000,
+                adult_age: 10_000,
+                elder_age: 100_000,
+                min_care: 150,
+            };
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_lifecycle_config(config.clone()),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_lifecycle_config(LifecycleConfig {
+                    hatch_age: 20_000,
+                    ..config.clone()
+                }),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert!(rmrk.set_lifecycle_config(config.clone()).is_ok());
+            assert_eq!(rmrk.get_lifecycle_config(), config);
+
+            for (asset_id, stage) in [
+                (1, LifecycleStage::Egg),
+                (2, LifecycleStage::Baby),
+                (3, LifecycleStage::Adult),
+                (4, LifecycleStage::Dead),
+            ] {
+                assert!(rmrk
+                    .add_asset_entry(None, asset_id, 0, "ipfs://pet/".into(), Vec::new())
+                    .is_ok());
+                assert!(rmrk.set_stage_asset(stage, Some(asset_id)).is_ok());
+            }
+            assert_eq!(
+                rmrk.set_stage_asset(LifecycleStage::Elder, Some(5)),
+                Err(RmrkError::AssetIdNotFound.into())
+            );
+            assert_eq!(rmrk.get_stage_asset(LifecycleStage::Baby), Some(2));
+            assert!(rmrk
+                .add_item(Item {
+                    name: "Phoenix Feather".into(),
+                    price: 0,
+                    effect: ItemEffect::Revive(FULL_STATUS),
+                    cooldown: 0,
+                })
+                .is_ok());
+
+            // the pet is born once its owner looks after it
+            set_timestamp(1_000);
+            set_sender(accounts.bob);
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_born_at(TOKEN_ID), 1_000);
+            assert_eq!(rmrk.get_accepted_token_assets(TOKEN_ID), Ok([1].to_vec()));
+            set_sender(accounts.alice);
+            assert!(rmrk.set_status(TOKEN_ID, 0, 100, 100).is_ok());
+
+            // each stage puts its asset first
+            set_timestamp(2_000);
+            set_sender(accounts.bob);
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_lifecycle_stage(TOKEN_ID), LifecycleStage::Baby);
+            assert_eq!(
+                rmrk.get_accepted_token_assets(TOKEN_ID),
+                Ok([2, 1].to_vec())
+            );
+            set_timestamp(11_000);
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_lifecycle_stage(TOKEN_ID), LifecycleStage::Adult);
+            assert_eq!(
+                rmrk.get_accepted_token_assets(TOKEN_ID),
+                Ok([3, 2, 1].to_vec())
+            );
+            assert!(rmrk.buy_item(1, 1).is_ok());
+            assert_eq!(
+                rmrk.use_item(TOKEN_ID, 1),
+                Err(RmrkError::PetIsNotDead.into())
+            );
+
+            // the owner has to accept the asset of a death caused by someone else
+            set_timestamp(12_000);
+            set_sender(accounts.alice);
+            assert!(rmrk.set_death_status(TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_lifecycle_stage(TOKEN_ID), LifecycleStage::Dead);
+            assert_eq!(rmrk.get_pending_token_assets(TOKEN_ID), Ok([4].to_vec()));
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.eat_an_apple(TOKEN_ID, accounts.bob),
+                Err(RmrkError::PetIsDead.into())
+            );
+            // a revived pet is born again and grows up from a baby
+            assert!(rmrk.use_item(TOKEN_ID, 1).is_ok());
+            assert_eq!(rmrk.get_status(TOKEN_ID), Some(FULL_STATUS));
+            assert_eq!(rmrk.get_born_at(TOKEN_ID), 12_000);
+            assert_eq!(rmrk.get_lifecycle_stage(TOKEN_ID), LifecycleStage::Baby);
+            assert_eq!(
+                rmrk.get_accepted_token_assets(TOKEN_ID),
+                Ok([2, 3, 1].to_vec())
+            );
+
+            set_timestamp(21_000);
+            assert_eq!(rmrk.get_lifecycle_stage(TOKEN_ID), LifecycleStage::Baby);
+            set_timestamp(22_000);
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_lifecycle_stage(TOKEN_ID), LifecycleStage::Adult);
+            assert_eq!(
+                rmrk.get_accepted_token_assets(TOKEN_ID),
+                Ok([3, 2, 1].to_vec())
+            );
+
+            set_timestamp(111_000);
+            assert_eq!(rmrk.get_lifecycle_stage(TOKEN_ID), LifecycleStage::Adult);
+            set_timestamp(112_000);
+            assert_eq!(rmrk.get_lifecycle_stage(TOKEN_ID), LifecycleStage::Elder);
+        }
+
+        #[ink::test]
+        fn pet_view_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert_eq!(rmrk.get_pet_view(TOKEN_ID), None);
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+
+            assert!(rmrk.set_status(TOKEN_ID, 80, 60, 60).is_ok());
+            assert_eq!(rmrk.get_total_status(TOKEN_ID), 40);
+            assert_eq!(rmrk.get_condition(TOKEN_ID), Condition::Bad);
+            assert_eq!(rmrk.get_mood(TOKEN_ID), Mood::Hungry);
+            assert!(rmrk.set_status(TOKEN_ID, 10, 100, 20).is_ok());
+            assert_eq!(rmrk.get_condition(TOKEN_ID), Condition::Normal);
+            assert_eq!(rmrk.get_mood(TOKEN_ID), Mood::Sad);
+            assert!(rmrk.set_status(TOKEN_ID, 0, 100, 50).is_ok());
+            assert_eq!(rmrk.get_mood(TOKEN_ID), Mood::Content);
+            assert!(rmrk.set_status(TOKEN_ID, 0, 100, 100).is_ok());
+            assert_eq!(rmrk.get_condition(TOKEN_ID), Condition::Good);
+            assert_eq!(rmrk.get_mood(TOKEN_ID), Mood::Happy);
+
+            // hunger can't turn the total negative
+            assert!(rmrk.set_status(TOKEN_ID, 100, 10, 10).is_ok());
+            assert_eq!(rmrk.get_total_status(TOKEN_ID), 0);
+
+            assert!(rmrk.set_status(TOKEN_ID, 0, 100, 100).is_ok());
+            assert!(rmrk.set_last_eaten(TOKEN_ID, 500).is_ok());
+            assert_eq!(
+                rmrk.get_pet_view(TOKEN_ID),
+                Some(PetView {
+                    status: FULL_STATUS,
+                    total_status: 200,
+                    condition: Condition::Good,
+                    mood: Mood::Happy,
+                    stage: LifecycleStage::Egg,
+                    last_eaten: 500,
+                    uri: rmrk.token_uri(TOKEN_ID),
+                })
+            );
+        }
+
+        #[ink::test]
+        fn condition_tiers_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let byte_id = Id::Bytes([0xff, 0x01].to_vec());
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk._mint_to(accounts.bob, byte_id.clone()).is_ok());
+            assert!(rmrk.set_bad_uri("ipfs://bad/".into()).is_ok());
+            assert!(rmrk.set_good_uri("ipfs://good/".into()).is_ok());
+
+            // without tiers the base uri of the condition is followed by the token id
+            assert!(rmrk.set_status(TOKEN_ID, 0, 100, 100).is_ok());
+            assert_eq!(rmrk.get_condition(TOKEN_ID), Condition::Good);
+            assert_eq!(rmrk.get_condition_tier(TOKEN_ID), 2);
+            assert_eq!(rmrk.token_uri(TOKEN_ID), String::from("ipfs://good/1"));
+            assert_eq!(rmrk.token_uri(byte_id), String::from("ipfs://bad/ff01"));
+            assert_eq!(rmrk.get_condition_asset(TOKEN_ID), None);
+
+            for (asset_id, uri) in [(1, "ipfs://sad"), (2, "ipfs://happy"), (3, "ipfs://party")] {
+                assert!(rmrk
+                    .add_asset_entry(None, asset_id, 0, uri.into(), Vec::new())
+                    .is_ok());
+            }
+            let tier = |threshold, asset_id| {
+                ConditionTier {
+                    threshold,
+                    asset_id,
+                }
+            };
+            let tiers = [tier(0, 1), tier(150, 2), tier(150, 3)].to_vec();
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_condition_tiers(tiers.clone()),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_condition_tiers([tier(150, 2), tier(0, 1)].to_vec()),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert_eq!(
+                rmrk.set_condition_tiers([tier(0, 4)].to_vec()),
+                Err(RmrkError::AssetIdNotFound.into())
+            );
+            assert!(rmrk.set_condition_tiers(tiers.clone()).is_ok());
+            assert_eq!(rmrk.get_condition_tiers(), tiers);
+
+            assert_eq!(rmrk.get_condition(TOKEN_ID), Condition::Good);
+            assert_eq!(rmrk.get_condition_tier(TOKEN_ID), 1);
+            assert_eq!(rmrk.get_condition_asset(TOKEN_ID), Some(2));
+            assert_eq!(rmrk.token_uri(TOKEN_ID), String::from("ipfs://happy"));
+            assert!(rmrk.set_status(TOKEN_ID, 50, 50, 50).is_ok());
+            assert_eq!(rmrk.get_condition_tier(TOKEN_ID), 0);
+            assert_eq!(rmrk.token_uri(TOKEN_ID), String::from("ipfs://sad"));
+
+            // the accepted asset of the tier with the highest priority is rendered
+            assert!(rmrk.set_status(TOKEN_ID, 0, 100, 100).is_ok());
+            set_sender(accounts.bob);
+            assert!(rmrk.add_asset_to_token(TOKEN_ID, 3, None).is_ok());
+            assert_eq!(rmrk.get_condition_asset(TOKEN_ID), Some(3));
+            assert_eq!(rmrk.token_uri(TOKEN_ID), String::from("ipfs://party"));
+        }
+
+        #[ink::test]
+        fn leaderboards_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let pets = [Id::U64(1), Id::U64(2), Id::U64(3)];
+            let owners = [accounts.bob, accounts.charlie, accounts.django];
+            assert_eq!(rmrk.get_leaderboard_size(), 10);
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_leaderboard_size(2),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_leaderboard_size(0),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert!(rmrk.set_leaderboard_size(2).is_ok());
+
+            set_timestamp(1_000);
+            for ((pet, owner), (status, money)) in
+                pets.iter()
+                    .zip(owners)
+                    .zip([(100, 300), (50, 150), (80, 200)])
+            {
+                assert!(rmrk._mint_to(owner, pet.clone()).is_ok());
+                assert!(rmrk.set_status(pet.clone(), 0, status, status).is_ok());
+                assert!(rmrk.set_your_money(owner, money).is_ok());
+            }
+            // staked currency counts towards wealth
+            set_sender(accounts.charlie);
+            assert!(rmrk.stake(100, None).is_ok());
+            assert_eq!(rmrk.get_staked_money(accounts.charlie), 100);
+            set_sender(accounts.alice);
+            assert!(rmrk.set_your_money(accounts.django, 120).is_ok());
+            for pet in pets.iter() {
+                assert!(rmrk.checkpoint(pet.clone()).is_ok());
+            }
+
+            let entry = |subject, score| LeaderboardEntry { subject, score };
+            let pet_board = [
+                (1, entry(LeaderboardSubject::Pet(pets[0].clone()), 200)),
+                (2, entry(LeaderboardSubject::Pet(pets[2].clone()), 160)),
+            ]
+            .to_vec();
+            assert_eq!(
+                rmrk.get_leaderboard(LeaderboardKind::PetStatus, 0, 10),
+                pet_board
+            );
+            assert_eq!(
+                rmrk.get_leaderboard(LeaderboardKind::PetStatus, 1, 1),
+                pet_board[1..].to_vec()
+            );
+            assert_eq!(
+                rmrk.get_leaderboard(LeaderboardKind::Wealth, 0, 10),
+                [
+                    (1, entry(LeaderboardSubject::Player(accounts.bob), 300)),
+                    (2, entry(LeaderboardSubject::Player(accounts.charlie), 150)),
+                ]
+                .to_vec()
+            );
+            assert_eq!(
+                rmrk.get_leaderboard(LeaderboardKind::BonusStreak, 0, 10),
+                Vec::new()
+            );
+
+            // ties keep the earlier entry first
+            set_sender(accounts.alice);
+            assert!(rmrk.set_status(pets[1].clone(), 0, 100, 100).is_ok());
+            assert!(rmrk.checkpoint(pets[1].clone()).is_ok());
+            assert_eq!(
+                rmrk.get_leaderboard(LeaderboardKind::PetStatus, 0, 10),
+                [
+                    (1, entry(LeaderboardSubject::Pet(pets[0].clone()), 200)),
+                    (2, entry(LeaderboardSubject::Pet(pets[1].clone()), 200)),
+                ]
+                .to_vec()
+            );
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.reset_leaderboards(),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert!(rmrk.reset_leaderboards().is_ok());
+            assert_eq!(rmrk.get_leaderboard_season(), 1);
+            assert_eq!(
+                rmrk.get_leaderboard(LeaderboardKind::PetStatus, 0, 10),
+                Vec::new()
+            );
+            assert_eq!(
+                rmrk.get_leaderboard_snapshot(0, LeaderboardKind::PetStatus, 0, 1),
+                [(1, entry(LeaderboardSubject::Pet(pets[0].clone()), 200))].to_vec()
+            );
+        }
+
+        #[ink::test]
+        fn currency_config_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert_eq!(rmrk.get_currency(), None);
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_currency(Some(accounts.django)),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert!(rmrk.set_currency(Some(accounts.django)).is_ok());
+            assert_eq!(rmrk.get_currency(), Some(accounts.django));
+
+            // without a currency the internal ledger is used
+            assert!(rmrk.set_currency(None).is_ok());
+            assert!(rmrk.set_your_money(accounts.bob, 20).is_ok());
+            set_timestamp(61_000);
+            set_sender(accounts.bob);
+            assert!(rmrk.daily_bonus(accounts.bob).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.bob), 120);
+
+            // with a currency the game master setters still only move the ledger
+            set_sender(accounts.alice);
+            assert!(rmrk.set_currency(Some(accounts.django)).is_ok());
+            assert!(rmrk.plus_your_money(accounts.bob, 5).is_ok());
+            assert_eq!(rmrk.get_season_money(0, accounts.bob), 125);
+            assert!(rmrk.minus_your_money(accounts.bob, 25).is_ok());
+            assert_eq!(rmrk.get_season_money(0, accounts.bob), 100);
+            assert_eq!(
+                rmrk.minus_your_money(accounts.bob, 101),
+                Err(RmrkError::NotEnoughMoney.into())
+            );
+            assert_eq!(rmrk.get_season_money(0, accounts.bob), 100);
+        }
+
+        #[ink::test]
+        fn staking_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let config = StakingConfig {
+                apr: 10_000,
+                lock_options: [LockOption {
+                    duration: YEAR,
+                    boost: 20_000,
+                }]
+                .to_vec(),
+            };
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_staking_config(config.clone()),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_staking_config(StakingConfig {
+                    apr: 10_000,
+                    lock_options: [LockOption {
+                        duration: YEAR,
+                        boost: 0,
+                    }]
+                    .to_vec(),
+                }),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert!(rmrk.set_staking_config(config.clone()).is_ok());
+            assert_eq!(rmrk.get_staking_config(), config);
+
+            set_timestamp(1_000);
+            assert!(rmrk.set_your_money(accounts.alice, 10_000).is_ok());
+            assert!(rmrk.set_your_money(accounts.bob, 2_000).is_ok());
+            assert!(rmrk.set_your_money(accounts.charlie, 1_000).is_ok());
+            assert!(rmrk.fund_staking_treasury(500).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.alice), 9_500);
+            assert_eq!(rmrk.get_staking_treasury(), 500);
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.stake(1_000, Some(1)),
+                Err(RmrkError::InvalidLockId.into())
+            );
+            assert!(rmrk.stake(1_000, None).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.bob), 1_000);
+            set_sender(accounts.charlie);
+            assert!(rmrk.stake(1_000, Some(0)).is_ok());
+            assert_eq!(rmrk.get_total_staked(), 2_000);
+
+            // the locked stake earns twice as much
+            set_timestamp(1_000 + YEAR / 10);
+            assert_eq!(rmrk.get_pending_staking_rewards(accounts.bob, None), 100);
+            assert_eq!(
+                rmrk.get_pending_staking_rewards(accounts.charlie, Some(0)),
+                200
+            );
+            assert_eq!(rmrk.get_staking_treasury(), 200);
+
+            // rewards stop once the treasury is empty
+            set_timestamp(1_000 + YEAR / 5);
+            assert_eq!(rmrk.get_pending_staking_rewards(accounts.bob, None), 166);
+            assert_eq!(
+                rmrk.get_pending_staking_rewards(accounts.charlie, Some(0)),
+                333
+            );
+            assert_eq!(rmrk.get_staking_treasury(), 1);
+
+            // partial unstake claims the rewards
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.unstake(1_001, None),
+                Err(RmrkError::NotEnoughStake.into())
+            );
+            assert!(rmrk.unstake(400, None).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.bob), 1_566);
+            assert_eq!(rmrk.get_stake(accounts.bob, None).unwrap().amount, 600);
+            assert_eq!(rmrk.get_pending_staking_rewards(accounts.bob, None), 0);
+
+            set_sender(accounts.charlie);
+            assert_eq!(rmrk.unstake(1, Some(0)), Err(RmrkError::StakeLocked.into()));
+            assert_eq!(rmrk.claim_staking_rewards(Some(0)), Ok(333));
+            assert_eq!(rmrk.get_your_money(accounts.charlie), 333);
+
+            set_timestamp(1_000 + YEAR);
+            assert!(rmrk.unstake(1_000, Some(0)).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.charlie), 1_333);
+            assert_eq!(rmrk.get_stake(accounts.charlie, Some(0)), None);
+        }
+
+        #[ink::test]
+        fn interactions_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let bob_pet = Id::U64(1);
+            let charlie_pet = Id::U64(2);
+            let egg = Id::U64(3);
+            assert!(rmrk._mint_to(accounts.bob, bob_pet.clone()).is_ok());
+            assert!(rmrk._mint_to(accounts.charlie, charlie_pet.clone()).is_ok());
+            assert!(rmrk._mint_to(accounts.bob, egg.clone()).is_ok());
+
+            // pets grow up as soon as they are born
+            assert!(rmrk
+                .set_lifecycle_config(LifecycleConfig {
+                    hatch_age: 0,
+                    adult_age: 0,
+                    min_care: 0,
+                    ..LifecycleConfig::default()
+                })
+                .is_ok());
+            assert!(rmrk.set_status(bob_pet.clone(), 50, 50, 50).is_ok());
+            assert!(rmrk.set_status(charlie_pet.clone(), 50, 50, 50).is_ok());
+            assert_eq!(
+                rmrk.get_lifecycle_stage(bob_pet.clone()),
+                LifecycleStage::Adult
+            );
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_play_effect(StatusDelta {
+                    hungry: 0,
+                    health: 0,
+                    happy: 50,
+                }),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.play_with(bob_pet.clone(), bob_pet.clone()),
+                Err(RmrkError::InvalidTokenId.into())
+            );
+            assert_eq!(
+                rmrk.play_with(bob_pet.clone(), egg.clone()),
+                Err(RmrkError::InvalidLifecycleStage.into())
+            );
+
+            // both owners have to agree
+            set_timestamp(1_000);
+            assert_eq!(
+                rmrk.play_with(bob_pet.clone(), charlie_pet.clone()),
+                Ok(false)
+            );
+            assert!(rmrk.has_consent(PetInteraction::Play, bob_pet.clone(), charlie_pet.clone()));
+            assert_eq!(rmrk.get_status(bob_pet.clone()).unwrap().happy, 50);
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.play_with(charlie_pet.clone(), bob_pet.clone()),
+                Ok(true)
+            );
+            assert!(!rmrk.has_consent(PetInteraction::Play, bob_pet.clone(), charlie_pet.clone()));
+            let played = Status {
+                hungry: 60,
+                health: 50,
+                happy: 70,
+            };
+            assert_eq!(rmrk.get_status(bob_pet.clone()), Some(played.clone()));
+            assert_eq!(rmrk.get_status(charlie_pet.clone()), Some(played.clone()));
+            assert_eq!(
+                rmrk.play_with(charlie_pet.clone(), bob_pet.clone()),
+                Err(RmrkError::TimeHasNotPassed(300_000).into())
+            );
+
+            // consents can be withdrawn
+            set_timestamp(2_000);
+            assert_eq!(rmrk.breed(charlie_pet.clone(), bob_pet.clone()), Ok(None));
+            assert!(rmrk
+                .withdraw_consent(PetInteraction::Breed, charlie_pet.clone(), bob_pet.clone())
+                .is_ok());
+            assert!(!rmrk.has_consent(PetInteraction::Breed, charlie_pet.clone(), bob_pet.clone()));
+
+            // consents don't follow the pet to its new owner
+            assert_eq!(rmrk.breed(charlie_pet.clone(), bob_pet.clone()), Ok(None));
+            assert!(rmrk
+                .transfer(accounts.django, charlie_pet.clone(), Vec::new())
+                .is_ok());
+            assert!(!rmrk.has_consent(PetInteraction::Breed, charlie_pet.clone(), bob_pet.clone()));
+            set_sender(accounts.bob);
+            assert_eq!(rmrk.breed(bob_pet.clone(), charlie_pet.clone()), Ok(None));
+            assert!(rmrk.has_consent(PetInteraction::Breed, bob_pet.clone(), charlie_pet.clone()));
+            assert!(rmrk
+                .withdraw_consent(PetInteraction::Breed, bob_pet.clone(), charlie_pet.clone())
+                .is_ok());
+            set_sender(accounts.django);
+            assert!(rmrk
+                .transfer(accounts.charlie, charlie_pet.clone(), Vec::new())
+                .is_ok());
+            set_sender(accounts.charlie);
+            assert!(!rmrk.has_consent(PetInteraction::Breed, charlie_pet.clone(), bob_pet.clone()));
+
+            // the child is minted to the game and waits in the parent
+            assert_eq!(rmrk.breed(charlie_pet.clone(), bob_pet.clone()), Ok(None));
+            rmrk.minting.max_supply = 0;
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.breed(bob_pet.clone(), charlie_pet.clone()),
+                Err(RmrkError::CollectionIsFull.into())
+            );
+            rmrk.minting.max_supply = 10;
+            rmrk.minting.last_token_id = 3;
+            set_sender(accounts.alice);
+            assert!(rmrk
+                .add_asset_entry(None, 1, 0, "ipfs://adult/".into(), Vec::new())
+                .is_ok());
+            assert!(rmrk.set_stage_asset(LifecycleStage::Adult, Some(1)).is_ok());
+            set_sender(accounts.bob);
+            assert_eq!(rmrk.breed(bob_pet.clone(), charlie_pet.clone()), Ok(None));
+            set_sender(accounts.charlie);
+            let child = Id::U64(4);
+            assert_eq!(
+                rmrk.breed(charlie_pet.clone(), bob_pet.clone()),
+                Ok(Some(child.clone()))
+            );
+            let contract = test::callee::<ink::env::DefaultEnvironment>();
+            assert_eq!(rmrk.owner_of(child.clone()), Some(contract));
+            assert_eq!(
+                rmrk.nesting.pending_children.get(charlie_pet.clone()),
+                Some(vec![(contract, child.clone())])
+            );
+            assert_eq!(
+                rmrk.nesting.accepted_children.get(charlie_pet.clone()),
+                None
+            );
+            assert_eq!(
+                rmrk.get_pending_token_assets(child.clone()),
+                Ok([1].to_vec())
+            );
+            // both parents have the same stats to pass on
+            assert_eq!(rmrk.get_status(child), Some(played));
+            assert_eq!(
+                rmrk.breed(charlie_pet.clone(), bob_pet.clone()),
+                Err(RmrkError::TimeHasNotPassed(86_400_000).into())
+            );
+        }
+
+        #[ink::test]
+        fn batch_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let other_pet = Id::U64(2);
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk._mint_to(accounts.bob, other_pet.clone()).is_ok());
+            assert!(rmrk._mint_to(accounts.charlie, Id::U64(3)).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 2).is_ok());
+            assert!(rmrk.set_your_money(accounts.bob, 1_000).is_ok());
+
+            set_timestamp(1_000);
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.feed_many([(TOKEN_ID, APPLE_ITEM_ID); MAX_BATCH_SIZE + 1].to_vec()),
+                Err(RmrkError::TooManyActions.into())
+            );
+            assert!(rmrk
+                .feed_many(
+                    [
+                        (TOKEN_ID, APPLE_ITEM_ID),
+                        (other_pet.clone(), APPLE_ITEM_ID)
+                    ]
+                    .to_vec()
+                )
+                .is_ok());
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 0);
+            assert_eq!(rmrk.get_last_eaten(TOKEN_ID), 1_000);
+            assert_eq!(rmrk.get_last_eaten(other_pet.clone()), 1_000);
+
+            // the batch stops at the first failing action
+            assert_eq!(
+                rmrk.execute_actions(
+                    [
+                        GameAction::BuyItem(APPLE_ITEM_ID, 1),
+                        GameAction::EatAnApple(Id::U64(3)),
+                    ]
+                    .to_vec()
+                ),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+
+            set_timestamp(100_000);
+            assert_eq!(
+                rmrk.execute_actions(
+                    [
+                        GameAction::BuyItem(APPLE_ITEM_ID, 1),
+                        GameAction::EatAnApple(TOKEN_ID),
+                        GameAction::Checkpoint(other_pet),
+                        GameAction::DailyBonus,
+                    ]
+                    .to_vec()
+                ),
+                Ok([
+                    ActionResult::Done,
+                    ActionResult::Done,
+                    ActionResult::Done,
+                    ActionResult::Claimed(100),
+                ]
+                .to_vec())
+            );
+            assert_eq!(rmrk.get_last_eaten(TOKEN_ID), 100_000);
+
+            // claim_all skips the bonus until its cooldown is over
+            set_sender(accounts.alice);
+            assert!(rmrk
+                .set_cooldown(Cooldown::DailyBonus, 24 * 60 * 60 * 1000)
+                .is_ok());
+            assert!(rmrk
+                .set_staking_config(StakingConfig {
+                    apr: 10_000,
+                    lock_options: Vec::new(),
+                })
+                .is_ok());
+            assert!(rmrk.set_your_money(accounts.alice, 1_000).is_ok());
+            assert!(rmrk.fund_staking_treasury(1_000).is_ok());
+            set_sender(accounts.bob);
+            assert!(rmrk.stake(1_000, None).is_ok());
+            assert_eq!(rmrk.claim_all(), Ok(0));
+            set_timestamp(100_000 + 12 * 60 * 60 * 1000);
+            assert_eq!(rmrk.claim_all(), Ok(1));
+            set_timestamp(100_000 + 24 * 60 * 60 * 1000);
+            assert_eq!(rmrk.claim_all(), Ok(100 + 1));
+            assert_eq!(rmrk.get_bonus_streak(accounts.bob), 2);
+        }
+
+        #[ink::test]
+        fn overflows_are_checked() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+
+            // stats stay within their bounds
+            assert_eq!(
+                rmrk.get_stat_bounds(),
+                StatBounds {
+                    max: DEFAULT_MAX_STAT
+                }
+            );
+            assert_eq!(
+                rmrk.set_status(TOKEN_ID, 10, u32::MAX - 10, u32::MAX),
+                Err(RmrkError::StatOutOfRange.into())
+            );
+            assert_eq!(
+                rmrk.set_status(TOKEN_ID, 0, 101, 0),
+                Err(RmrkError::StatOutOfRange.into())
+            );
+            assert_eq!(rmrk.get_status(TOKEN_ID), None);
+            assert!(rmrk.set_status(TOKEN_ID, 10, 90, 100).is_ok());
+            assert!(rmrk.add_twenty(TOKEN_ID).is_ok());
+            assert!(rmrk.change_some_status(TOKEN_ID, u32::MAX).is_ok());
+            assert_eq!(rmrk.get_status(TOKEN_ID), Some(FULL_STATUS));
+            assert_eq!(rmrk.get_total_status(TOKEN_ID), 200);
+
+            // lowering the maximum clamps the stats already stored
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_stat_bounds(StatBounds { max: 50 }),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_stat_bounds(StatBounds { max: 0 }),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert!(rmrk.set_stat_bounds(StatBounds { max: 50 }).is_ok());
+            let half_status = Status {
+                hungry: 0,
+                health: 50,
+                happy: 50,
+            };
+            assert_eq!(rmrk.get_status(TOKEN_ID), Some(half_status.clone()));
+            assert_eq!(rmrk.get_current_status(TOKEN_ID), Some(half_status.clone()));
+            // the preset statuses are validated like any other status
+            assert_eq!(
+                rmrk.set_full_status(TOKEN_ID),
+                Err(RmrkError::StatOutOfRange.into())
+            );
+            assert_eq!(
+                rmrk.set_death_status(TOKEN_ID),
+                Err(RmrkError::StatOutOfRange.into())
+            );
+            assert_eq!(rmrk.get_status(TOKEN_ID), Some(half_status));
+            assert!(rmrk.set_stat_bounds(StatBounds::default()).is_ok());
+
+            // currency never wraps
+            assert!(rmrk.set_your_money(accounts.bob, u64::MAX).is_ok());
+            assert_eq!(
+                rmrk.plus_your_money(accounts.bob, 1),
+                Err(RmrkError::MoneyOverflow.into())
+            );
+            assert_eq!(rmrk.get_your_money(accounts.bob), u64::MAX);
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.daily_bonus(accounts.bob),
+                Err(RmrkError::MoneyOverflow.into())
+            );
+            assert!(rmrk.stake(u64::MAX, None).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.bob), 0);
+
+            set_sender(accounts.alice);
+            assert!(rmrk.set_your_money(accounts.alice, 1).is_ok());
+            assert_eq!(rmrk.stake(1, None), Err(RmrkError::MoneyOverflow.into()));
+            assert_eq!(rmrk.get_your_money(accounts.alice), 1);
+            assert_eq!(rmrk.get_total_staked(), u64::MAX);
+
+            assert!(rmrk.set_your_money(accounts.alice, u64::MAX).is_ok());
+            assert!(rmrk.fund_staking_treasury(u64::MAX).is_ok());
+            assert!(rmrk.set_your_money(accounts.alice, 1).is_ok());
+            assert_eq!(
+                rmrk.fund_staking_treasury(1),
+                Err(RmrkError::MoneyOverflow.into())
+            );
+            assert_eq!(rmrk.get_your_money(accounts.alice), 1);
+        }
+
+        #[ink::test]
+        fn status_changes_stay_within_bounds() {
+            let bounds = StatBounds::default();
+            let edges = [i32::MIN, i32::MIN + 1, i32::MAX - 1, i32::MAX];
+            // every stat value against every delta up to twice the range, and the extremes
+            for value in 0..=bounds.max {
+                for delta in (-2 * bounds.max as i32..=2 * bounds.max as i32).chain(edges) {
+                    let status = Status {
+                        hungry: value,
+                        health: value,
+                        happy: value,
+                    };
+                    let changed = status.apply(
+                        &StatusDelta {
+                            hungry: delta,
+                            health: delta.saturating_neg(),
+                            happy: delta,
+                        },
+                        &bounds,
+                    );
+                    let expected = |delta: i32| {
+                        (value as i64 + delta as i64).clamp(0, bounds.max as i64) as u32
+                    };
+                    assert_eq!(
+                        changed,
+                        Status {
+                            hungry: expected(delta),
+                            health: expected(delta.saturating_neg()),
+                            happy: expected(delta),
+                        }
+                    );
+                    assert!(bounds.contains(&changed));
+                }
+            }
+        }
+
+        #[ink::test]
+        fn extreme_timestamps_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk.set_status(TOKEN_ID, 0, 100, 100).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 100).is_ok());
+            assert!(rmrk
+                .set_staking_config(StakingConfig {
+                    apr: u32::MAX,
+                    lock_options: Vec::new(),
+                })
+                .is_ok());
+            assert!(rmrk.set_your_money(accounts.alice, 1_000).is_ok());
+            assert!(rmrk.fund_staking_treasury(1_000).is_ok());
+            assert!(rmrk.set_your_money(accounts.bob, 1_000).is_ok());
+            set_sender(accounts.bob);
+            assert!(rmrk.stake(500, None).is_ok());
+
+            // both ends and a pseudo-random point of every power of two up to u64::MAX
+            let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+            let mut timestamps = [YEAR, u64::MAX].to_vec();
+            for bit in 0..64 {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                let low = 1u64 << bit;
+                timestamps.extend([low - 1, low, low + seed % low, low.saturating_mul(2) - 1]);
+            }
+            timestamps.sort_unstable();
+            timestamps.dedup();
+
+            let pet = CooldownSubject::Token(TOKEN_ID);
+            let bounds = rmrk.get_stat_bounds();
+            for timestamp in timestamps {
+                set_timestamp(timestamp);
+                assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+                // the pet may not survive that long, which only fails the feeding
+                let _ = rmrk.eat_an_apple(TOKEN_ID, accounts.bob);
+                assert!(rmrk.get_cooldown_remaining(Cooldown::Feed, pet.clone()) <= 60_000);
+                assert!(rmrk.get_last_checkpoint(TOKEN_ID) <= timestamp);
+                assert!(bounds.contains(&rmrk.get_current_status(TOKEN_ID).unwrap()));
+                assert!(rmrk.get_pet_view(TOKEN_ID).is_some());
+                assert!(rmrk.claim_all().is_ok());
+                assert!(rmrk.get_staking_treasury() <= 1_000);
+            }
+
+            // rewards never exceed the treasury
+            assert_eq!(rmrk.get_staking_treasury(), 0);
+            assert!(rmrk.unstake(500, None).is_ok());
+            assert_eq!(rmrk.get_total_staked(), 0);
+            assert!(rmrk.get_your_money(accounts.bob) >= 1_000 + 1_000);
+        }
+
+        #[ink::test]
+        fn shop_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let sale = Listing {
+                item_id: APPLE_ITEM_ID,
+                price: 30,
+                currency: ShopCurrency::Ledger,
+                stock: Some(3),
+                starts_at: 1_000,
+                ends_at: Some(5_000),
+                limit_per_account: Some(2),
+            };
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.add_listing(sale.clone()),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.add_listing(Listing {
+                    item_id: 9,
+                    ..sale.clone()
+                }),
+                Err(RmrkError::ItemNotFound.into())
+            );
+            assert_eq!(
+                rmrk.add_listing(Listing {
+                    ends_at: Some(1_000),
+                    ..sale.clone()
+                }),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert_eq!(rmrk.add_listing(sale.clone()), Ok(1));
+            assert_eq!(rmrk.get_listing(1), Some(sale.clone()));
+            assert_eq!(rmrk.get_active_listings(0, 10), Vec::new());
+            assert!(rmrk.set_your_money(accounts.bob, 1_000).is_ok());
+            assert!(rmrk.set_your_money(accounts.charlie, 1_000).is_ok());
+
+            // the sale only runs for a limited time
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.buy_listing(2, 1),
+                Err(RmrkError::ListingNotFound.into())
+            );
+            assert_eq!(rmrk.buy_listing(1, 1), Err(RmrkError::SaleNotActive.into()));
+            set_timestamp(1_000);
+            assert_eq!(
+                rmrk.get_active_listings(0, 10),
+                [(1, sale.clone())].to_vec()
+            );
+            assert!(rmrk.buy_listing(1, 2).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.bob), 940);
+            assert_eq!(rmrk.get_item_balance(accounts.bob, APPLE_ITEM_ID), 2);
+            assert_eq!(rmrk.get_purchased(1, accounts.bob), 2);
+            assert_eq!(
+                rmrk.buy_listing(1, 1),
+                Err(RmrkError::PurchaseLimitReached.into())
+            );
+            set_sender(accounts.charlie);
+            assert_eq!(rmrk.buy_listing(1, 2), Err(RmrkError::OutOfStock.into()));
+            assert!(rmrk.buy_listing(1, 1).is_ok());
+            assert_eq!(rmrk.get_listing(1).unwrap().stock, Some(0));
+            set_timestamp(5_000);
+            assert_eq!(rmrk.buy_listing(1, 1), Err(RmrkError::SaleNotActive.into()));
+
+            // native listings are paid with the value transferred
+            set_sender(accounts.alice);
+            let native_sale = Listing {
+                currency: ShopCurrency::Native,
+                stock: None,
+                ends_at: None,
+                limit_per_account: None,
+                ..sale
+            };
+            assert_eq!(rmrk.add_listing(native_sale.clone()), Ok(2));
+            // listings are paged by id, whether they are on sale or not
+            assert_eq!(rmrk.get_active_listings(0, 1), Vec::new());
+            assert_eq!(
+                rmrk.get_active_listings(1, 2),
+                [(2, native_sale.clone())].to_vec()
+            );
+            assert_eq!(rmrk.get_active_listings(2, 1), [(2, native_sale)].to_vec());
+            assert_eq!(rmrk.get_active_listings(3, 10), Vec::new());
+            set_sender(accounts.bob);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(30);
+            assert_eq!(
+                rmrk.buy_listing(2, 2),
+                Err(RmrkError::InvalidPayment.into())
+            );
+            assert_eq!(
+                rmrk.buy_listing(1, 1),
+                Err(RmrkError::InvalidPayment.into())
+            );
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(60);
+            assert!(rmrk.buy_listing(2, 2).is_ok());
+            assert_eq!(rmrk.get_item_balance(accounts.bob, APPLE_ITEM_ID), 4);
+            assert_eq!(rmrk.get_shop_revenue(ShopCurrency::Native), 60);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            // the ledger revenue goes to the admin
+            assert_eq!(rmrk.get_shop_revenue(ShopCurrency::Ledger), 90);
+            assert_eq!(
+                rmrk.withdraw_shop_revenue(ShopCurrency::Ledger),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(rmrk.withdraw_shop_revenue(ShopCurrency::Ledger), Ok(90));
+            assert_eq!(rmrk.get_your_money(accounts.alice), 90);
+            assert_eq!(rmrk.get_shop_revenue(ShopCurrency::Ledger), 0);
+            // the native revenue is withdrawn with the balance of the contract
+            assert_eq!(rmrk.withdraw_shop_revenue(ShopCurrency::Native), Ok(60));
+            assert_eq!(rmrk.get_shop_revenue(ShopCurrency::Native), 0);
+
+            assert!(rmrk.remove_listing(2).is_ok());
+            assert_eq!(rmrk.get_listing(2), None);
+            assert_eq!(
+                rmrk.remove_listing(2),
+                Err(RmrkError::ListingNotFound.into())
+            );
+        }
+
+        #[ink::test]
+        fn ledger_listings_ignore_the_currency() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk.set_currency(Some(accounts.django)).is_ok());
+            let sale = Listing {
+                item_id: APPLE_ITEM_ID,
+                price: 30,
+                currency: ShopCurrency::Ledger,
+                stock: None,
+                starts_at: 0,
+                ends_at: None,
+                limit_per_account: None,
+            };
+            assert_eq!(rmrk.add_listing(sale), Ok(1));
+            assert!(rmrk.set_your_money(accounts.bob, 100).is_ok());
+
+            // the ledger pays for the listing, never the PSP22 balance
+            set_sender(accounts.bob);
+            assert!(rmrk.buy_listing(1, 2).is_ok());
+            assert_eq!(rmrk.get_season_money(0, accounts.bob), 40);
+            assert_eq!(rmrk.get_item_balance(accounts.bob, APPLE_ITEM_ID), 2);
+            assert_eq!(
+                rmrk.buy_listing(1, 2),
+                Err(RmrkError::NotEnoughMoney.into())
+            );
+            assert_eq!(rmrk.get_season_money(0, accounts.bob), 40);
+
+            set_sender(accounts.alice);
+            assert_eq!(rmrk.withdraw_shop_revenue(ShopCurrency::Ledger), Ok(60));
+            assert_eq!(rmrk.get_season_money(0, accounts.alice), 60);
+        }
+
+        #[ink::test]
+        fn quests_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 2).is_ok());
+            let fed = FeedOutcome {
+                weight: 1,
+                effect: StatusEffect::Set(FULL_STATUS),
+            };
+            assert!(rmrk.set_feed_outcomes([fed].to_vec()).is_ok());
+            assert!(rmrk
+                .set_decay_config(DecayConfig {
+                    health_rate: 0,
+                    ..DecayConfig::default()
+                })
+                .is_ok());
+
+            let feed_quest = Quest {
+                name: String::from("Feed twice"),
+                condition: QuestCondition::Feed(2),
+                money: 50,
+                items: [(APPLE_ITEM_ID, 1)].to_vec(),
+                achievement: Some(String::from("well_fed")),
+            };
+            let health_quest = Quest {
+                name: String::from("Stay healthy"),
+                condition: QuestCondition::KeepHealth {
+                    health: 80,
+                    days: 3,
+                },
+                money: 0,
+                items: Vec::new(),
+                achievement: None,
+            };
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.add_quest(feed_quest.clone()),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.add_quest(Quest {
+                    items: [(9, 1)].to_vec(),
+                    ..feed_quest.clone()
+                }),
+                Err(RmrkError::ItemNotFound.into())
+            );
+            assert_eq!(
+                rmrk.add_quest(Quest {
+                    condition: QuestCondition::KeepHealth {
+                        health: 80,
+                        days: HEALTH_DAYS_KEPT + 1,
+                    },
+                    ..health_quest.clone()
+                }),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert_eq!(rmrk.add_quest(feed_quest.clone()), Ok(1));
+            assert_eq!(rmrk.add_quest(health_quest.clone()), Ok(2));
+            assert_eq!(
+                rmrk.get_quests(0, 10),
+                [(1, feed_quest.clone()), (2, health_quest.clone())].to_vec()
+            );
+            assert_eq!(rmrk.get_quests(0, 1), [(1, feed_quest)].to_vec());
+            assert_eq!(rmrk.get_quests(2, 10), [(2, health_quest)].to_vec());
+            assert_eq!(rmrk.get_quests(3, 10), Vec::new());
+
+            // feeding counts towards the quest of the account
+            set_timestamp(DAY);
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.claim_quest(3, TOKEN_ID),
+                Err(RmrkError::QuestNotFound.into())
+            );
+            assert_eq!(
+                rmrk.get_quest_progress(1, accounts.bob, TOKEN_ID),
+                Ok((0, 2))
+            );
+            assert!(rmrk.eat_an_apple(TOKEN_ID, accounts.bob).is_ok());
+            assert_eq!(
+                rmrk.claim_quest(1, TOKEN_ID),
+                Err(RmrkError::QuestNotCompleted.into())
+            );
+            set_timestamp(DAY + 60_000);
+            assert!(rmrk.eat_an_apple(TOKEN_ID, accounts.bob).is_ok());
+            assert_eq!(rmrk.get_feed_count(accounts.bob), 2);
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.claim_quest(1, TOKEN_ID),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+            set_sender(accounts.bob);
+            assert!(rmrk.claim_quest(1, TOKEN_ID).is_ok());
+            assert_eq!(
+                rmrk.get_quest_claimed_at(1, accounts.bob, TOKEN_ID),
+                Some(DAY + 60_000)
+            );
+            assert_eq!(rmrk.get_your_money(accounts.bob), 50);
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 1);
+            assert_eq!(
+                rmrk.get_attribute(TOKEN_ID, String::from("well_fed")),
+                Some(String::from("1"))
+            );
+            assert_eq!(
+                rmrk.claim_quest(1, TOKEN_ID),
+                Err(RmrkError::QuestAlreadyClaimed.into())
+            );
+
+            // health counts on the days the status of the pet was updated
+            assert_eq!(
+                rmrk.get_quest_progress(2, accounts.bob, TOKEN_ID),
+                Ok((1, 3))
+            );
+            set_timestamp(2 * DAY);
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            set_timestamp(3 * DAY);
+            assert_eq!(
+                rmrk.get_quest_progress(2, accounts.bob, TOKEN_ID),
+                Ok((0, 3))
+            );
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            assert_eq!(
+                rmrk.get_quest_progress(2, accounts.bob, TOKEN_ID),
+                Ok((3, 3))
+            );
+
+            // a low of the day breaks the streak even once the pet recovered
+            set_sender(accounts.alice);
+            assert!(rmrk.set_status(TOKEN_ID, 0, 70, 100).is_ok());
+            assert!(rmrk.set_full_status(TOKEN_ID).is_ok());
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.get_quest_progress(2, accounts.bob, TOKEN_ID),
+                Ok((0, 3))
+            );
+            assert_eq!(
+                rmrk.claim_quest(2, TOKEN_ID),
+                Err(RmrkError::QuestNotCompleted.into())
+            );
+            for day in 4..=6 {
+                set_timestamp(day * DAY);
+                assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            }
+            assert!(rmrk.claim_quest(2, TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.bob), 50);
+
+            // the health quest stays claimed for the pet once it changed hands,
+            // while the feeding quest is still open to its new owner
+            assert!(rmrk._burn_from(accounts.bob, TOKEN_ID).is_ok());
+            assert!(rmrk._mint_to(accounts.charlie, TOKEN_ID).is_ok());
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.get_quest_claimed_at(2, accounts.charlie, TOKEN_ID),
+                Some(6 * DAY)
+            );
+            assert_eq!(
+                rmrk.claim_quest(2, TOKEN_ID),
+                Err(RmrkError::QuestAlreadyClaimed.into())
+            );
+            assert_eq!(
+                rmrk.get_quest_claimed_at(1, accounts.charlie, TOKEN_ID),
+                None
+            );
+            assert_eq!(
+                rmrk.claim_quest(1, TOKEN_ID),
+                Err(RmrkError::QuestNotCompleted.into())
+            );
+
+            set_sender(accounts.alice);
+            assert!(rmrk.remove_quest(2).is_ok());
+            assert_eq!(rmrk.get_quest(2), None);
+            assert_eq!(
+                rmrk.get_quest_progress(2, accounts.bob, TOKEN_ID),
+                Err(RmrkError::QuestNotFound.into())
+            );
+
+            // stakes only count while they stay locked long enough
+            let stake_quest = Quest {
+                name: String::from("Stake for a week"),
+                condition: QuestCondition::Stake {
+                    amount: 100,
+                    lock: 7 * DAY,
+                },
+                money: 10,
+                items: Vec::new(),
+                achievement: None,
+            };
+            assert_eq!(
+                rmrk.add_quest(Quest {
+                    condition: QuestCondition::Stake {
+                        amount: 100,
+                        lock: 0,
+                    },
+                    ..stake_quest.clone()
+                }),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert_eq!(rmrk.add_quest(stake_quest), Ok(3));
+            assert!(rmrk
+                .set_staking_config(StakingConfig {
+                    apr: 10_000,
+                    lock_options: [LockOption {
+                        duration: 10 * DAY,
+                        boost: 10_000,
+                    }]
+                    .to_vec(),
+                })
+                .is_ok());
+            assert!(rmrk.set_your_money(accounts.charlie, 300).is_ok());
+            set_sender(accounts.charlie);
+            assert!(rmrk.stake(100, None).is_ok());
+            assert_eq!(
+                rmrk.get_quest_progress(3, accounts.charlie, TOKEN_ID),
+                Ok((0, 100))
+            );
+            assert!(rmrk.stake(100, Some(0)).is_ok());
+            assert_eq!(
+                rmrk.get_quest_progress(3, accounts.charlie, TOKEN_ID),
+                Ok((100, 100))
+            );
+            set_timestamp(10 * DAY);
+            assert_eq!(
+                rmrk.get_quest_progress(3, accounts.charlie, TOKEN_ID),
+                Ok((0, 100))
+            );
+            assert_eq!(
+                rmrk.claim_quest(3, TOKEN_ID),
+                Err(RmrkError::QuestNotCompleted.into())
+            );
+            assert!(rmrk.stake(100, Some(0)).is_ok());
+            assert!(rmrk.claim_quest(3, TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.charlie), 10);
+        }
+
+        #[ink::test]
+        fn rate_limit_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk.set_your_money(accounts.bob, 1_000).is_ok());
+            assert!(rmrk.set_your_money(accounts.charlie, 1_000).is_ok());
+            let budget = ActionBudget {
+                max_calls: 2,
+                window: RateWindow::Time(60_000),
+            };
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_action_budget("buy".into(), Some(budget.clone())),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_action_budget(
+                    "buy".into(),
+                    Some(ActionBudget {
+                        window: RateWindow::Blocks(0),
+                        ..budget.clone()
+                    })
+                ),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert!(rmrk
+                .set_action_budget("buy".into(), Some(budget.clone()))
+                .is_ok());
+            assert_eq!(rmrk.get_action_budget("buy".into()), Some(budget));
+            assert_eq!(
+                rmrk.get_remaining_calls("buy".into(), accounts.bob),
+                Some(2)
+            );
+            assert_eq!(rmrk.get_remaining_calls("feed".into(), accounts.bob), None);
+
+            // each account has its own budget in the window
+            set_timestamp(1_000);
+            set_sender(accounts.bob);
+            assert!(rmrk.buy_item(APPLE_ITEM_ID, 1).is_ok());
+            assert!(rmrk.buy_item(APPLE_ITEM_ID, 1).is_ok());
+            assert_eq!(
+                rmrk.buy_item(APPLE_ITEM_ID, 1),
+                Err(RmrkError::RateLimited.into())
+            );
+            assert_eq!(
+                rmrk.get_remaining_calls("buy".into(), accounts.bob),
+                Some(0)
+            );
+            set_sender(accounts.charlie);
+            assert!(rmrk.buy_item(APPLE_ITEM_ID, 1).is_ok());
+            set_timestamp(61_000);
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.get_remaining_calls("buy".into(), accounts.bob),
+                Some(2)
+            );
+            assert!(rmrk.buy_item(APPLE_ITEM_ID, 1).is_ok());
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 3);
+
+            // staking, batches and the other player messages have their own budgets
+            let once = ActionBudget {
+                max_calls: 1,
+                window: RateWindow::Blocks(1),
+            };
+            set_sender(accounts.alice);
+            for action in ["stake", "batch", "checkpoint", "consent"] {
+                assert!(rmrk
+                    .set_action_budget(action.into(), Some(once.clone()))
+                    .is_ok());
+            }
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            set_sender(accounts.bob);
+            assert!(rmrk.stake(10, None).is_ok());
+            assert_eq!(rmrk.unstake(10, None), Err(RmrkError::RateLimited.into()));
+            assert!(rmrk.claim_all().is_ok());
+            assert_eq!(rmrk.claim_all(), Err(RmrkError::RateLimited.into()));
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            assert_eq!(
+                rmrk.execute_actions([GameAction::Checkpoint(TOKEN_ID)].to_vec()),
+                Err(RmrkError::RateLimited.into())
+            );
+            test::advance_block::<ink::env::DefaultEnvironment>();
+            // the actions of a batch count on their own
+            assert_eq!(
+                rmrk.execute_actions(
+                    [
+                        GameAction::Checkpoint(TOKEN_ID),
+                        GameAction::Checkpoint(TOKEN_ID),
+                    ]
+                    .to_vec()
+                ),
+                Err(RmrkError::RateLimited.into())
+            );
+            test::advance_block::<ink::env::DefaultEnvironment>();
+            assert!(rmrk.unstake(10, None).is_ok());
+            assert_eq!(
+                rmrk.fund_staking_treasury(10),
+                Err(RmrkError::RateLimited.into())
+            );
+            let other_pet = Id::U64(2);
+            assert!(rmrk
+                .withdraw_consent(PetInteraction::Play, TOKEN_ID, other_pet.clone())
+                .is_ok());
+            assert_eq!(
+                rmrk.withdraw_consent(PetInteraction::Breed, TOKEN_ID, other_pet),
+                Err(RmrkError::RateLimited.into())
+            );
+
+            // the cap of the block counts the calls of every account
+            set_sender(accounts.alice);
+            assert!(rmrk.set_action_budget("buy".into(), None).is_ok());
+            assert!(rmrk.set_block_cap(Some(2)).is_ok());
+            assert_eq!(rmrk.get_block_cap(), Some(2));
+            test::advance_block::<ink::env::DefaultEnvironment>();
+            set_sender(accounts.bob);
+            assert!(rmrk.buy_item(APPLE_ITEM_ID, 1).is_ok());
+            set_sender(accounts.charlie);
+            assert!(rmrk.buy_item(APPLE_ITEM_ID, 1).is_ok());
+            assert_eq!(
+                rmrk.buy_item(APPLE_ITEM_ID, 1),
+                Err(RmrkError::BlockCapReached.into())
+            );
+            test::advance_block::<ink::env::DefaultEnvironment>();
+            assert!(rmrk.buy_item(APPLE_ITEM_ID, 1).is_ok());
+        }
+
+        #[ink::test]
+        fn status_change_emits_event() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert!(recorded_events().is_empty());
+
+            assert!(rmrk.set_status(TOKEN_ID, 10, 20, 30).is_ok());
+            let status = Status {
+                hungry: 10,
+                health: 20,
+                happy: 30,
+            };
+            assert!(matches!(
+                recorded_events().as_slice(),
+                [Event::StatusChanged(StatusChanged { token, status: emitted })]
+                    if *token == TOKEN_ID && *emitted == status
+            ));
+
+            // failed updates emit nothing
+            set_sender(accounts.bob);
+            assert!(rmrk.set_status(TOKEN_ID, 0, 0, 0).is_err());
+            assert_eq!(recorded_events().len(), 1);
+        }
+
+        #[ink::test]
+        fn status_history_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk._mint_to(accounts.bob, TOKEN_ID).is_ok());
+            assert_eq!(
+                rmrk.get_status_history_capacity(),
+                DEFAULT_STATUS_HISTORY_CAPACITY
+            );
+            assert_eq!(rmrk.get_status_history(TOKEN_ID, 0, 10), Vec::new());
+
+            set_timestamp(60_000);
+            assert!(rmrk.set_full_status(TOKEN_ID).is_ok());
+            let set_record = StatusRecord {
+                timestamp: 60_000,
+                status: FULL_STATUS,
+                cause: StatusCause::GameMaster,
+            };
+            set_timestamp(120_000);
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            let decay_record = StatusRecord {
+                timestamp: 120_000,
+                status: Status {
+                    hungry: 5,
+                    health: 95,
+                    happy: 95,
+                },
+                cause: StatusCause::Decay,
+            };
+            // checkpoints that don't change the status aren't recorded
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_status_history_len(TOKEN_ID), 2);
+            assert_eq!(
+                rmrk.get_status_history(TOKEN_ID, 0, 10),
+                [(0, set_record), (1, decay_record.clone())].to_vec()
+            );
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_status_history_capacity(2),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_status_history_capacity(0),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert_eq!(
+                rmrk.set_status_history_capacity(MAX_STATUS_HISTORY_CAPACITY + 1),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert!(rmrk.set_status_history_capacity(2).is_ok());
+            assert_eq!(rmrk.get_status_history_capacity(), 2);
+
+            // the oldest entry is dropped once the history is full
+            assert!(rmrk.add_twenty(TOKEN_ID).is_ok());
+            let change_record = StatusRecord {
+                timestamp: 120_000,
+                status: FULL_STATUS,
+                cause: StatusCause::GameMaster,
+            };
+            assert_eq!(rmrk.get_status_history_len(TOKEN_ID), 3);
+            assert_eq!(
+                rmrk.get_status_history(TOKEN_ID, 0, 10),
+                [(1, decay_record.clone()), (2, change_record.clone())].to_vec()
+            );
+            assert_eq!(
+                rmrk.get_status_history(TOKEN_ID, 0, 1),
+                [(1, decay_record)].to_vec()
+            );
+            assert_eq!(
+                rmrk.get_status_history(TOKEN_ID, 2, 10),
+                [(2, change_record.clone())].to_vec()
+            );
+            assert_eq!(rmrk.get_status_history(TOKEN_ID, 3, 10), Vec::new());
+
+            // entries dropped by a lower capacity don't come back once it is raised
+            assert!(rmrk.set_status_history_capacity(1).is_ok());
+            assert_eq!(
+                rmrk.get_status_history(TOKEN_ID, 0, 10),
+                [(2, change_record.clone())].to_vec()
+            );
+            assert!(rmrk.set_status_history_capacity(3).is_ok());
+            assert!(rmrk.set_status_history_capacity(10).is_ok());
+            assert_eq!(
+                rmrk.get_status_history(TOKEN_ID, 0, 10),
+                [(2, change_record.clone())].to_vec()
+            );
+
+            // they are removed once the history grows, which the new capacity applies to
+            set_timestamp(180_000);
+            assert!(rmrk.checkpoint(TOKEN_ID).is_ok());
+            assert_eq!(rmrk.get_status_history_len(TOKEN_ID), 4);
+            assert_eq!(rmrk.petgame.status_history.get((TOKEN_ID, 1u32)), None);
+            let history = rmrk.get_status_history(TOKEN_ID, 0, 10);
+            assert_eq!(history.len(), 2);
+            assert_eq!(history[0], (2, change_record));
+            assert_eq!(history[1].0, 3);
+        }
+
+        #[ink::test]
+        fn seasons_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk.set_your_money(accounts.bob, 1_000).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 10).is_ok());
+            set_sender(accounts.bob);
+            assert!(rmrk.stake(100, None).is_ok());
+            assert_eq!(rmrk.get_season(), 0);
+
+            let carry_over = CarryOver {
+                money: 1_000,
+                items: 5_000,
+                stakes: true,
+            };
+            assert_eq!(
+                rmrk.start_new_season(Some(carry_over.clone())),
+                Err(AccessControlError::MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.start_new_season(Some(CarryOver {
+                    money: 10_001,
+                    ..carry_over.clone()
+                })),
+                Err(RmrkError::BadConfig.into())
+            );
+
+            // accounts keep their share of the balances in the new season
+            assert!(rmrk.start_new_season(Some(carry_over.clone())).is_ok());
+            assert_eq!(rmrk.get_season(), 1);
+            assert_eq!(rmrk.get_carry_over(0), Some(carry_over));
+            assert_eq!(rmrk.get_carry_over(1), None);
+            assert_eq!(rmrk.get_your_money(accounts.bob), 90);
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 5);
+            assert_eq!(rmrk.get_staked_money(accounts.bob), 100);
+            assert_eq!(rmrk.get_stake(accounts.bob, None).unwrap().amount, 100);
+            assert_eq!(rmrk.get_season_money(0, accounts.bob), 900);
+            assert_eq!(rmrk.get_season_items(0, accounts.bob, APPLE_ITEM_ID), 10);
+
+            // nothing is kept without a carry-over
+            assert!(rmrk.start_new_season(None).is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.bob), 0);
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 0);
+            assert_eq!(rmrk.get_staked_money(accounts.bob), 0);
+            assert_eq!(rmrk.get_stake(accounts.bob, None), None);
+            assert_eq!(rmrk.get_total_staked(), 0);
+            assert_eq!(rmrk.get_staking_treasury(), 100);
+            assert_eq!(rmrk.get_season_staked_money(1, accounts.bob), 100);
+            assert_eq!(rmrk.get_season_money(3, accounts.bob), 0);
+
+            // past seasons keep the balances they ended with
+            assert!(rmrk.set_your_money(accounts.bob, 30).is_ok());
+            assert_eq!(rmrk.get_season_money(0, accounts.bob), 900);
+            assert_eq!(rmrk.get_season_money(1, accounts.bob), 90);
+            assert_eq!(rmrk.get_season_money(2, accounts.bob), 30);
+            set_sender(accounts.bob);
+            assert!(rmrk.stake(10, None).is_ok());
+            assert_eq!(rmrk.get_stake(accounts.bob, None).unwrap().amount, 10);
+            assert_eq!(rmrk.get_total_staked(), 10);
+            assert_eq!(rmrk.get_season_staked_money(1, accounts.bob), 100);
+            assert_eq!(rmrk.get_season_staked_money(2, accounts.bob), 10);
+        }
+
+        #[ink::test]
+        fn seasons_keep_stakes_with_a_currency() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk.set_your_money(accounts.bob, 1_000).is_ok());
+            set_sender(accounts.bob);
+            assert!(rmrk.stake(100, None).is_ok());
+
+            // the game holds the staked tokens, so they are never reset
+            set_sender(accounts.alice);
+            assert!(rmrk.set_currency(Some(accounts.django)).is_ok());
+            assert!(rmrk.start_new_season(None).is_ok());
+            assert_eq!(
+                rmrk.get_carry_over(0),
+                Some(CarryOver {
+                    money: 0,
+                    items: 0,
+                    stakes: true,
+                })
+            );
+            assert_eq!(rmrk.get_stake(accounts.bob, None).unwrap().amount, 100);
+            assert_eq!(rmrk.get_staked_money(accounts.bob), 100);
+            assert_eq!(rmrk.get_total_staked(), 100);
+            assert_eq!(rmrk.get_staking_treasury(), 0);
+            assert_eq!(rmrk.get_season_money(1, accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn seasons_carry_balances_lazily() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk.set_your_money(accounts.bob, 1 << 40).is_ok());
+            assert!(rmrk.set_your_apple(accounts.bob, 1_000).is_ok());
+            let keep_all = CarryOver {
+                money: 10_000,
+                items: 10_000,
+                stakes: true,
+            };
+            for _ in 0..100 {
+                assert!(rmrk.start_new_season(Some(keep_all.clone())).is_ok());
+            }
+            assert!(rmrk
+                .start_new_season(Some(CarryOver {
+                    money: 5_000,
+                    items: 1_000,
+                    stakes: true,
+                }))
+                .is_ok());
+            assert_eq!(rmrk.get_your_money(accounts.bob), 1 << 39);
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 100);
+
+            // the skipped seasons are not stored when the balance changes
+            assert!(rmrk.set_your_money(accounts.bob, 7).is_ok());
+            assert_eq!(rmrk.petgame.money_season_counts.get(accounts.bob), Some(2));
+            assert_eq!(rmrk.get_season_money(0, accounts.bob), 1 << 40);
+            assert_eq!(rmrk.get_season_money(50, accounts.bob), 1 << 40);
+            assert_eq!(rmrk.get_season_money(100, accounts.bob), 1 << 40);
+            assert_eq!(rmrk.get_season_money(101, accounts.bob), 7);
+
+            // balances keep their share over the epochs the index starts over in
+            for _ in 0..35 {
+                assert!(rmrk
+                    .start_new_season(Some(CarryOver {
+                        money: 5_000,
+                        items: 10_000,
+                        stakes: true,
+                    }))
+                    .is_ok());
+            }
+            assert_eq!(rmrk.petgame.season_indexes.get(136).unwrap().money.epoch, 1);
+            assert_eq!(rmrk.get_your_money(accounts.bob), 0);
+            assert_eq!(rmrk.get_season_money(102, accounts.bob), 3);
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 100);
+            assert!(rmrk.start_new_season(None).is_ok());
+            assert_eq!(rmrk.get_your_apple(accounts.bob), 0);
+            assert_eq!(rmrk.get_season_items(136, accounts.bob, APPLE_ITEM_ID), 100);
+        }
+    }
+}
@@ -16,7 +16,10 @@ use openbrush::{
     },
 };
 
-use rmrk_common::roles::CONTRIBUTOR;
+use rmrk_common::roles::{
+    CONTRIBUTOR,
+    GAME_MASTER,
+};
 use rmrk_minting::{
     self,
     traits::MintingLazy,
@@ -61,6 +64,7 @@ where
 {
     instance._init_with_admin(account);
     instance._setup_role(CONTRIBUTOR, account);
+    instance._setup_role(GAME_MASTER, account);
 }
 
 pub fn with_contributor<T>(instance: &mut T, account: AccountId)
@@ -70,6 +74,13 @@ where
     instance._setup_role(CONTRIBUTOR, account);
 }
 
+pub fn with_game_master<T>(instance: &mut T, account: AccountId)
+where
+    T: access_control::Internal + Storage<access_control::Data>,
+{
+    instance._setup_role(GAME_MASTER, account);
+}
+
 fn _with_royalties<T>(_instance: &mut T, _royalty_receiver: AccountId, _royalty: u8) {
     todo!()
 }
@@ -1,91 +1,489 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![feature(min_specialization)]
 
+/// Game controller for a pet collection.
+///
+/// The pets stay in their RMRK collection. This contract keeps the game state and runs the
+/// rules of the pet game, reaching the pets through the collection, so the game can be
+/// replaced without migrating the collection. It needs the contributor role of the
+/// collection to mint pets and set their attributes and its base URI.
 #[openbrush::contract]
-pub mod catalog_example {
+pub mod tamago {
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::{
+            string::String as PreludeString,
+            vec::Vec,
+        },
+    };
     use openbrush::{
-        contracts::access_control::*,
+        contracts::{
+            access_control::*,
+            psp34::{
+                Id,
+                PSP34Error,
+            },
+            traits::psp34::PSP34Ref,
+        },
+        modifiers,
         traits::{
             Storage,
             String,
         },
     };
 
-    use rmrk_catalog::{
-        catalog::*,
-        roles::*,
+    use rmrk::{
+        config,
+        errors::{
+            Result,
+            RmrkError,
+        },
+        roles::ADMIN,
+        storage::{
+            collection::PetCollection,
+            *,
+        },
         traits::*,
+        types::*,
+        utils::*,
     };
 
+    /// Event emitted when the stored status of a pet changes.
+    #[ink(event)]
+    pub struct StatusChanged {
+        #[ink(topic)]
+        token: Id,
+        status: Status,
+    }
+
+    /// Event emitted when a feeding rolls an outcome.
+    #[ink(event)]
+    pub struct FeedOutcomeRolled {
+        #[ink(topic)]
+        token: Id,
+        index: u32,
+        effect: StatusEffect,
+    }
+
+    /// Event emitted when an item is used on a pet.
+    #[ink(event)]
+    pub struct ItemUsed {
+        #[ink(topic)]
+        token: Id,
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        item: ItemId,
+    }
+
+    /// Event emitted when items are bought.
+    #[ink(event)]
+    pub struct ItemBought {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        item: ItemId,
+        qty: u32,
+        price: u64,
+    }
+
+    /// Event emitted when a pet moves to another stage of life.
+    #[ink(event)]
+    pub struct LifecycleChanged {
+        #[ink(topic)]
+        token: Id,
+        stage: LifecycleStage,
+    }
+
+    /// Event emitted when two pets play together.
+    #[ink(event)]
+    pub struct PetsPlayed {
+        #[ink(topic)]
+        token: Id,
+        #[ink(topic)]
+        other_token: Id,
+    }
+
+    /// Event emitted when two pets have a child.
+    #[ink(event)]
+    pub struct PetBred {
+        #[ink(topic)]
+        parent: Id,
+        #[ink(topic)]
+        other_parent: Id,
+        #[ink(topic)]
+        child: Id,
+    }
+
+    /// Event emitted when the daily bonus is claimed.
+    #[ink(event)]
+    pub struct BonusClaimed {
+        #[ink(topic)]
+        account: AccountId,
+        streak: u32,
+        reward: BonusReward,
+    }
+
+    /// Event emitted when a shop listing is bought.
+    #[ink(event)]
+    pub struct ListingBought {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        listing: ListingId,
+        qty: u32,
+        price: Balance,
+    }
+
+    /// Event emitted when currency is staked.
+    #[ink(event)]
+    pub struct Staked {
+        #[ink(topic)]
+        account: AccountId,
+        lock: Option<u32>,
+        amount: u64,
+    }
+
+    /// Event emitted when currency is withdrawn from a stake.
+    #[ink(event)]
+    pub struct Unstaked {
+        #[ink(topic)]
+        account: AccountId,
+        lock: Option<u32>,
+        amount: u64,
+        rewards: u64,
+    }
 
-    use core::time::Duration;
+    /// Event emitted when staking rewards are claimed.
+    #[ink(event)]
+    pub struct StakingRewardsClaimed {
+        #[ink(topic)]
+        account: AccountId,
+        lock: Option<u32>,
+        rewards: u64,
+    }
 
-    const FIVE_MINUTES: u64 = 5 * 60;
+    /// Event emitted when a quest is claimed.
+    #[ink(event)]
+    pub struct QuestClaimed {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        quest: QuestId,
+        id: Id,
+    }
 
-    // CatalogContract contract storage
+    // Tamago contract storage
     #[ink(storage)]
-    #[derive(Default, Storage)]
-    pub struct CatalogContract {
+    #[derive(Storage)]
+    pub struct Tamago {
         #[storage_field]
         access: access_control::Data,
         #[storage_field]
-        catalog: CatalogData,
-        hunger: u32,
-        happiness: u32,
-        health: u32,
-        last_fed: Timestamp,
-        last_played: Timestamp, 
+        petgame: PetGameData,
+        #[storage_field]
+        staking: StakingData,
+        #[storage_field]
+        shop: ShopData,
+        #[storage_field]
+        quests: QuestData,
+        #[storage_field]
+        rate_limit: RateLimitData,
+        collection: AccountId,
     }
 
-    pub struct CatalogContract1 {
-        hunger1: u32,
-        happiness1: u32,
-        health1: u32,
-    }
+    impl AccessControl for Tamago {}
 
-    impl Catalog for CatalogContract {}
+    impl PetGame for Tamago {}
 
-    impl CatalogContract {
-        
-        /// Instantiate new CatalogContract contract
-        #[ink(constructor)]
-        pub fn new(catalog_metadata: String) -> Self {
-            let mut instance = CatalogContract::default();
-            let admin = Self::env().caller();
-            instance._init_with_admin(admin);
-            instance._setup_role(CONTRIBUTOR, admin);
-            _ = Catalog::setup_catalog(&mut instance, catalog_metadata);
+    impl Staking for Tamago {}
+
+    impl Batch for Tamago {}
+
+    impl Shop for Tamago {}
+
+    impl Quests for Tamago {}
+
+    impl RateLimit for Tamago {}
+
+    impl Seasons for Tamago {}
 
+    impl Interactions for Tamago {}
+
+    impl Tamago {
+        /// Instantiate new Tamago contract for the pets of `collection`
+        #[ink(constructor)]
+        pub fn new(collection: AccountId) -> Self {
+            let mut instance = Self {
+                access: Default::default(),
+                petgame: Default::default(),
+                staking: Default::default(),
+                shop: Default::default(),
+                quests: Default::default(),
+                rate_limit: Default::default(),
+                collection,
+            };
+            config::with_admin(&mut instance, Self::env().caller());
             instance
         }
 
+        /// Used to retrieve the collection of the pets.
         #[ink(message)]
-        pub fn feed(&mut self) {
-            let current_time = self.env().block_timestamp();
-            let time_since_last_fed = current_time - self.last_fed;
-            let five_minutes = Duration::from_secs(FIVE_MINUTES);
-        
-            if Duration::from_millis(time_since_last_fed) >= five_minutes {
-                self.hunger = self.hunger.saturating_sub(20);
-                self.happiness = self.happiness.saturating_add(10);
-                self.health = self.health.saturating_add(5);
-                self.last_fed = current_time;
+        pub fn get_collection(&self) -> AccountId {
+            self.collection
+        }
+    }
+
+    impl Utils for Tamago {
+        /// The base URI belongs to the collection
+        fn set_base_uri(&mut self, uri: PreludeString) -> Result<()> {
+            UtilsRef::set_base_uri(&self.collection, uri)
+        }
+
+        /// Withdraw contract's balance
+        #[modifiers(only_role(ADMIN))]
+        fn withdraw(&mut self) -> Result<()> {
+            let balance = Self::env().balance();
+            let caller = Self::env().caller();
+            let current_balance = balance
+                .checked_sub(Self::env().minimum_balance())
+                .unwrap_or_default();
+            Self::env()
+                .transfer(caller, current_balance)
+                .map_err(|_| RmrkError::WithdrawalFailed)?;
+            Ok(())
+        }
+
+        /// The attributes of the pets belong to the collection
+        fn set_token_attribute(&mut self, id: Id, key: String, value: String) -> Result<()> {
+            UtilsRef::set_token_attribute(&self.collection, id, key, value)
+        }
+
+        /// Check if the pet is minted in the collection. Return the owner
+        fn ensure_exists_and_get_owner(&self, id: &Id) -> Result<AccountId> {
+            let token_owner = self.pet_owner(id).ok_or(PSP34Error::TokenNotExists)?;
+            Ok(token_owner)
+        }
+
+        /// Ensure that the caller is the token owner
+        fn ensure_token_owner(&self, token_owner: AccountId) -> Result<()> {
+            if Self::env().caller() != token_owner {
+                return Err(RmrkError::NotTokenOwner.into())
             }
+            Ok(())
         }
+    }
 
-        
+    impl PetCollection for Tamago {
+        fn pet_owner(&self, token_id: &Id) -> Option<AccountId> {
+            PSP34Ref::owner_of(&self.collection, token_id.clone())
+        }
 
-        #[ink(message)]
-        pub fn get_happiness(&self) -> u32 {
-            self.happiness
+        fn pet_accepted_assets(&self, token_id: &Id) -> Vec<AssetId> {
+            MultiAssetRef::get_accepted_token_assets(&self.collection, token_id.clone())
+                .unwrap_or_default()
         }
-        #[ink(message)]
-        pub fn get_last_fed(&self) -> u64 {
-            self.last_fed
+
+        fn pet_pending_assets(&self, token_id: &Id) -> Vec<AssetId> {
+            MultiAssetRef::get_pending_token_assets(&self.collection, token_id.clone())
+                .unwrap_or_default()
+        }
+
+        fn collection_asset_uri(&self, asset_id: AssetId) -> Option<String> {
+            MultiAssetRef::get_asset_uri(&self.collection, asset_id)
+        }
+
+        fn _add_pet_asset(&mut self, token_id: &Id, asset_id: AssetId) -> Result<()> {
+            MultiAssetRef::add_asset_to_token(&self.collection, token_id.clone(), asset_id, None)
+        }
+
+        /// The collection only lets the owner of a pet reorder its assets, so this fails
+        /// with `NotTokenOwner` and the owner sets the priority of the stage assets there
+        fn _set_pet_priority(&mut self, token_id: &Id, priorities: Vec<AssetId>) -> Result<()> {
+            MultiAssetRef::set_priority(&self.collection, token_id.clone(), priorities)
+        }
+
+        fn _set_pet_attribute(&mut self, token_id: &Id, key: String, value: String) -> Result<()> {
+            UtilsRef::set_token_attribute(&self.collection, token_id.clone(), key, value)
+        }
+
+        fn _mint_pet(&mut self, to: AccountId) -> Result<Id> {
+            MintingRef::mint(&self.collection, to)
+        }
+
+        /// `add_child` moves the child from the controller into the collection,
+        /// so the controller approves the collection to transfer it first
+        fn _mint_pet_child(&mut self, parent_id: &Id) -> Result<Id> {
+            let child_id = self._mint_pet(Self::env().account_id())?;
+            PSP34Ref::approve(
+                &self.collection,
+                self.collection,
+                Some(child_id.clone()),
+                true,
+            )?;
+            NestingRef::add_child(
+                &self.collection,
+                parent_id.clone(),
+                (self.collection, child_id.clone()),
+            )?;
+            Ok(child_id)
+        }
+    }
+
+    impl PetGameEvents for Tamago {
+        /// Emit StatusChanged event
+        fn _emit_status_changed_event(&self, token_id: &Id, status: &Status) {
+            self.env().emit_event(StatusChanged {
+                token: token_id.clone(),
+                status: status.clone(),
+            });
+        }
+
+        /// Emit FeedOutcomeRolled event
+        fn _emit_feed_outcome_event(&self, token_id: &Id, index: u32, effect: &StatusEffect) {
+            self.env().emit_event(FeedOutcomeRolled {
+                token: token_id.clone(),
+                index,
+                effect: effect.clone(),
+            });
+        }
+
+        /// Emit ItemUsed event
+        fn _emit_item_used_event(&self, token_id: &Id, account_id: &AccountId, item_id: ItemId) {
+            self.env().emit_event(ItemUsed {
+                token: token_id.clone(),
+                account: *account_id,
+                item: item_id,
+            });
+        }
+
+        /// Emit LifecycleChanged event
+        fn _emit_lifecycle_changed_event(&self, token_id: &Id, stage: LifecycleStage) {
+            self.env().emit_event(LifecycleChanged {
+                token: token_id.clone(),
+                stage,
+            });
+        }
+
+        /// Emit PetsPlayed event
+        fn _emit_pets_played_event(&self, token_id: &Id, other_token_id: &Id) {
+            self.env().emit_event(PetsPlayed {
+                token: token_id.clone(),
+                other_token: other_token_id.clone(),
+            });
+        }
+
+        /// Emit PetBred event
+        fn _emit_pet_bred_event(&self, parent_id: &Id, other_parent_id: &Id, child_id: &Id) {
+            self.env().emit_event(PetBred {
+                parent: parent_id.clone(),
+                other_parent: other_parent_id.clone(),
+                child: child_id.clone(),
+            });
+        }
+
+        /// Emit BonusClaimed event
+        fn _emit_bonus_claimed_event(
+            &self,
+            account_id: &AccountId,
+            streak: u32,
+            reward: &BonusReward,
+        ) {
+            self.env().emit_event(BonusClaimed {
+                account: *account_id,
+                streak,
+                reward: reward.clone(),
+            });
+        }
+
+        /// Emit ItemBought event
+        fn _emit_item_bought_event(
+            &self,
+            account_id: &AccountId,
+            item_id: ItemId,
+            qty: u32,
+            price: u64,
+        ) {
+            self.env().emit_event(ItemBought {
+                account: *account_id,
+                item: item_id,
+                qty,
+                price,
+            });
+        }
+
+        /// Emit ListingBought event
+        fn _emit_listing_bought_event(
+            &self,
+            account_id: &AccountId,
+            listing_id: ListingId,
+            qty: u32,
+            price: Balance,
+        ) {
+            self.env().emit_event(ListingBought {
+                account: *account_id,
+                listing: listing_id,
+                qty,
+                price,
+            });
+        }
+
+        /// Emit Staked event
+        fn _emit_staked_event(&self, account_id: &AccountId, lock_id: Option<u32>, amount: u64) {
+            self.env().emit_event(Staked {
+                account: *account_id,
+                lock: lock_id,
+                amount,
+            });
+        }
+
+        /// Emit Unstaked event
+        fn _emit_unstaked_event(
+            &self,
+            account_id: &AccountId,
+            lock_id: Option<u32>,
+            amount: u64,
+            rewards: u64,
+        ) {
+            self.env().emit_event(Unstaked {
+                account: *account_id,
+                lock: lock_id,
+                amount,
+                rewards,
+            });
+        }
+
+        /// Emit StakingRewardsClaimed event
+        fn _emit_staking_rewards_claimed_event(
+            &self,
+            account_id: &AccountId,
+            lock_id: Option<u32>,
+            rewards: u64,
+        ) {
+            self.env().emit_event(StakingRewardsClaimed {
+                account: *account_id,
+                lock: lock_id,
+                rewards,
+            });
+        }
+
+        /// Emit QuestClaimed event
+        fn _emit_quest_claimed_event(
+            &self,
+            account_id: &AccountId,
+            quest_id: QuestId,
+            token_id: &Id,
+        ) {
+            self.env().emit_event(QuestClaimed {
+                account: *account_id,
+                quest: quest_id,
+                id: token_id.clone(),
+            });
         }
-        
     }
-    
 
     #[cfg(test)]
     mod tests {
@@ -93,168 +491,145 @@ pub mod catalog_example {
 
         use ink::env::test;
 
-        use openbrush::contracts::psp34::extensions::enumerable::*;
-        use rmrk_catalog::{
-            errors::*,
-            roles::ADMIN,
-            traits::Catalog,
-            types::*,
+        use rmrk::{
+            errors::RmrkError,
+            roles::{
+                ADMIN,
+                GAME_MASTER,
+            },
         };
 
-        const METADATA: &str = "ipfs://myIpfsUri/";
-        const EQUIPPABLE_ADDRESS1: [u8; 32] = [1; 32];
-        const EQUIPPABLE_ADDRESS2: [u8; 32] = [2; 32];
-        const EQUIPPABLE_ADDRESS3: [u8; 32] = [3; 32];
+        const COLLECTION: [u8; 32] = [1; 32];
+
+        fn init() -> Tamago {
+            Tamago::new(COLLECTION.into())
+        }
 
         #[ink::test]
         fn role_works() {
-            let catalog = init();
+            let mut tamago = init();
             let accounts = default_accounts();
-            assert!(catalog.has_role(ADMIN, accounts.alice));
-        }
-
-        fn init() -> CatalogContract {
-            CatalogContract::new(String::from(METADATA).into())
-        }
+            assert!(tamago.has_role(ADMIN, accounts.alice));
+            assert!(tamago.has_role(GAME_MASTER, accounts.alice));
+            assert_eq!(tamago.get_collection(), AccountId::from(COLLECTION));
 
-        #[ink::test]
-        fn add_parts_to_catalog_works() {
-            const ASSET_URI: &str = "asset_uri/";
-            const ASSET_ID: AssetId = 1;
-            const TOKEN_ID1: Id = Id::U64(1);
-            const TOKEN_ID2: Id = Id::U64(2);
-            const PART_ID0: PartId = 0;
-            const PART_ID1: PartId = 1;
-
-            // Create 2 parts,
-            // The first is equippable and can accept 2 equipment from 2 contracts
-            // The second is fixed and can't accept any equipment
-            let part_list = vec![
-                Part {
-                    part_type: PartType::Slot,
-                    z: 0,
-                    equippable: vec![EQUIPPABLE_ADDRESS1.into(), EQUIPPABLE_ADDRESS2.into()],
-                    part_uri: String::from("ipfs://backgrounds/1.svg"),
-                    is_equippable_by_all: false,
-                },
-                Part {
-                    part_type: PartType::Fixed,
-                    z: 0,
-                    equippable: vec![],
-                    part_uri: String::from("ipfs://backgrounds/2.svg"),
-                    is_equippable_by_all: false,
-                },
-            ];
-
-            let mut catalog = init();
-
-            // verify add/get parts
-            assert!(catalog.get_parts_count() == 0);
-            assert!(catalog.add_part_list(part_list.clone()).is_ok());
-            assert_eq!(catalog.get_parts_count(), part_list.len() as u32);
-            assert_eq!(catalog.get_part(PART_ID0).unwrap().z, part_list[0].z);
-            assert_eq!(
-                catalog.get_part(PART_ID0).unwrap().part_uri,
-                part_list[0].part_uri
-            );
-            assert_eq!(
-                catalog.get_part(PART_ID0).unwrap().part_uri,
-                part_list[0].part_uri
-            );
-            assert_eq!(catalog.get_part(PART_ID0).unwrap().equippable.len(), 2);
-            assert_eq!(catalog.get_part(PART_ID1).unwrap().equippable.len(), 0);
-
-            // verify array of equippable addresses
-            assert!(catalog
-                .ensure_equippable(PART_ID0, EQUIPPABLE_ADDRESS1.into())
-                .is_ok());
-            assert!(catalog
-                .ensure_equippable(PART_ID0, EQUIPPABLE_ADDRESS2.into())
-                .is_ok());
-            assert!(catalog
-                .ensure_equippable(PART_ID1, EQUIPPABLE_ADDRESS2.into())
-                .is_err());
-
-            // verify setting and resetting equippable list
-            assert!(!catalog.is_equippable_by_all(PART_ID0));
-            assert!(catalog.set_equippable_by_all(PART_ID0).is_ok());
-            assert!(catalog.is_equippable_by_all(PART_ID0));
-            assert!(!catalog.is_equippable_by_all(42));
-            assert!(catalog.reset_equippable_addresses(PART_ID0).is_ok());
-            assert!(!catalog.is_equippable_by_all(PART_ID0));
-            assert!(catalog
-                .ensure_equippable(PART_ID0, EQUIPPABLE_ADDRESS1.into())
-                .is_err());
-            assert!(catalog
-                .add_equippable_addresses(
-                    PART_ID0,
-                    vec![EQUIPPABLE_ADDRESS1.into(), EQUIPPABLE_ADDRESS2.into()]
-                )
-                .is_ok());
-            assert!(catalog
-                .ensure_equippable(PART_ID0, EQUIPPABLE_ADDRESS1.into())
-                .is_ok());
-            assert_eq!(
-                catalog.add_equippable_addresses(PART_ID1, vec![EQUIPPABLE_ADDRESS1.into()]),
-                Err(RmrkError::PartIsNotSlot.into())
-            );
+            set_sender(accounts.bob);
             assert_eq!(
-                catalog.reset_equippable_addresses(PART_ID1),
-                Err(RmrkError::PartIsNotSlot.into())
+                tamago.set_your_money(accounts.bob, 100),
+                Err(AccessControlError::MissingRole.into())
             );
             assert_eq!(
-                catalog.set_equippable_by_all(PART_ID1),
-                Err(RmrkError::PartIsNotSlot.into())
+                tamago.set_stage_asset(LifecycleStage::Adult, None),
+                Err(AccessControlError::MissingRole.into())
             );
-
-            assert!(catalog
-                .ensure_equippable(PART_ID0, EQUIPPABLE_ADDRESS3.into())
-                .is_err());
         }
 
         #[ink::test]
-        fn test_bad_configuration() {
-            let mut catalog = init();
-
-            // Create 2 part lists. Both have 1 part and are invalid,
-            let bad_part_list1 = vec![Part {
-                part_type: PartType::Fixed,
-                z: 0,
-                equippable: vec![EQUIPPABLE_ADDRESS1.into()],
-                part_uri: String::from("ipfs://backgrounds/2.svg"),
-                is_equippable_by_all: false,
-            }];
-            let bad_part_list2 = vec![Part {
-                part_type: PartType::Fixed,
-                z: 0,
-                equippable: vec![],
-                part_uri: String::from("ipfs://backgrounds/2.svg"),
-                is_equippable_by_all: true,
-            }];
+        fn economy_works() {
+            let mut tamago = init();
+            let accounts = default_accounts();
+            assert!(tamago.set_your_money(accounts.bob, 100).is_ok());
+            set_timestamp(61_000);
 
+            set_sender(accounts.charlie);
             assert_eq!(
-                catalog.add_part_list(bad_part_list1.clone()),
-                Err(RmrkError::BadConfig.into())
+                tamago.buy_an_apple(accounts.bob),
+                Err(RmrkError::InvalidAccountId.into())
             );
+            set_sender(accounts.bob);
+            assert!(tamago.buy_an_apple(accounts.bob).is_ok());
+            assert_eq!(tamago.get_your_apple(accounts.bob), 1);
+            assert_eq!(tamago.get_your_money(accounts.bob), 80);
+            assert!(matches!(
+                recorded_events().last(),
+                Some(Event::ItemBought(ItemBought {
+                    account,
+                    item: APPLE_ITEM_ID,
+                    qty: 1,
+                    price: 20,
+                })) if *account == accounts.bob
+            ));
             assert_eq!(
-                catalog.add_part_list(bad_part_list2.clone()),
-                Err(RmrkError::BadConfig.into())
+                tamago.buy_item(APPLE_ITEM_ID, 5),
+                Err(RmrkError::NotEnoughMoney.into())
             );
+
+            assert!(tamago.stake(50, None).is_ok());
+            assert_eq!(tamago.get_your_money(accounts.bob), 30);
+            assert_eq!(tamago.get_staked_money(accounts.bob), 50);
+            assert_eq!(tamago.get_total_staked(), 50);
+            assert!(matches!(
+                recorded_events().last(),
+                Some(Event::Staked(Staked {
+                    account,
+                    lock: None,
+                    amount: 50,
+                })) if *account == accounts.bob
+            ));
+            assert!(tamago.unstake(20, None).is_ok());
+            assert_eq!(tamago.get_your_money(accounts.bob), 50);
+            assert_eq!(tamago.get_staked_money(accounts.bob), 30);
+            assert!(matches!(
+                recorded_events().last(),
+                Some(Event::Unstaked(Unstaked {
+                    account,
+                    lock: None,
+                    amount: 20,
+                    rewards: 0,
+                })) if *account == accounts.bob
+            ));
+
+            set_sender(accounts.alice);
+            assert!(tamago.start_new_season(None).is_ok());
+            assert_eq!(tamago.get_season(), 1);
+            assert_eq!(tamago.get_your_money(accounts.bob), 0);
+            assert_eq!(tamago.get_your_apple(accounts.bob), 0);
+            assert_eq!(tamago.get_staked_money(accounts.bob), 0);
+            assert_eq!(tamago.get_season_money(0, accounts.bob), 50);
+        }
+
+        // The off-chain environment can't call another contract, so reaching the
+        // collection panics. These tests make sure the pets are not looked up locally.
+
+        #[ink::test]
+        #[should_panic]
+        fn pet_owner_is_read_from_collection() {
+            let tamago = init();
+            tamago.is_nft_owner(Id::U64(1));
         }
 
         #[ink::test]
-        fn setting_metadata_works() {
-            let mut catalog = init();
+        #[should_panic]
+        fn pet_asset_is_added_through_collection() {
+            let mut tamago = init();
+            let _ = tamago._add_pet_asset(&Id::U64(1), 1);
+        }
 
-            assert_eq!(catalog.get_catalog_metadata(), METADATA);
-            assert!(catalog
-                .setup_catalog(String::from("ipfs://catalog_metadata2"))
-                .is_ok());
-            assert_eq!(catalog.get_catalog_metadata(), "ipfs://catalog_metadata2");
+        #[ink::test]
+        #[should_panic]
+        fn pet_priority_is_set_through_collection() {
+            let mut tamago = init();
+            let _ = tamago._set_pet_priority(&Id::U64(1), vec![1]);
         }
 
         fn default_accounts() -> test::DefaultAccounts<ink::env::DefaultEnvironment> {
             test::default_accounts::<Environment>()
         }
+
+        fn set_sender(sender: AccountId) {
+            test::set_caller::<Environment>(sender);
+        }
+
+        fn set_timestamp(timestamp: u64) {
+            test::set_block_timestamp::<Environment>(timestamp);
+        }
+
+        type Event = <Tamago as ink::reflect::ContractEventBase>::Type;
+
+        fn recorded_events() -> Vec<Event> {
+            test::recorded_events()
+                .map(|event| <Event as scale::Decode>::decode(&mut &event.data[..]).unwrap())
+                .collect()
+        }
     }
 }